@@ -1,9 +1,17 @@
-use crate::disassembler::DisassemblerTrait;
+use crate::{disassembler::DisassemblerTrait, memory::BinaryBuffer};
 
 pub mod mos6510;
+pub mod trace;
+#[cfg(test)]
+mod opcode_reference;
 
 type Mnemonic = &'static str;
 
 pub trait CpuTrait : DisassemblerTrait {
     fn set_pc(&mut self, pc:u32);
+    fn get_pc(&self) -> u32;
+    fn get_memory(&self) -> &BinaryBuffer;
+    fn set_undocumented_opcodes(&mut self, enable:bool);
+    // Indirizzi dei vettori hardware che contengono punti di ingresso.
+    fn hardware_vectors(&self) -> &'static [u32];
 }
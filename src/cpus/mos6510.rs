@@ -6,11 +6,16 @@ use crate::memory::BinaryBuffer;
 mod opcodes6510;
 mod addressing;
 mod disassembler;
+pub mod registers;
+pub mod emulator;
+#[cfg(test)]
+mod tests;
 
 #[derive(Debug,Default)]
 pub struct Cpu6510 {
     pc: u16,
-    memory: BinaryBuffer
+    memory: BinaryBuffer,
+    undocumented_opcodes: bool
 }
 
 
@@ -18,6 +23,23 @@ impl CpuTrait for Cpu6510 {
     fn set_pc(&mut self, pc:u32) {
         self.pc = pc as u16;
     }
+
+    fn get_pc(&self) -> u32 {
+        self.pc as u32
+    }
+
+    fn get_memory(&self) -> &BinaryBuffer {
+        &self.memory
+    }
+
+    fn set_undocumented_opcodes(&mut self, enable:bool) {
+        self.undocumented_opcodes = enable;
+    }
+
+    // NMI, RESET e IRQ/BRK.
+    fn hardware_vectors(&self) -> &'static [u32] {
+        &[0xFFFA, 0xFFFC, 0xFFFE]
+    }
 }
 
 
@@ -26,6 +48,7 @@ impl Cpu6510 {
         Self {
             pc: memory.get_loaded_address() as u16,
             memory,
+            undocumented_opcodes: false,
         }
     }
 }
@@ -21,17 +21,29 @@ impl AddressingMode {
         match addressing {
             AddressingMode::AddrImplied   | 
             AddressingMode::AddrAccumulator => String::from(""),
-            AddressingMode::AddrImmediate => String::from(format!("#${:02X}",value)),
-            AddressingMode::AddrZeroPage  => String::from(format!("${:02X}",value)),
-            AddressingMode::AddrZeroPageX => String::from(format!("${:02X},X",value)),
-            AddressingMode::AddrZeroPageY => String::from(format!("${:02X},Y",value)),
-            AddressingMode::AddrAbsolute => String::from(format!("${:04X}",value)),
-            AddressingMode::AddrAbsoluteX => String::from(format!("${:04X},X",value)),
-            AddressingMode::AddrAbsoluteY => String::from(format!("${:04X},Y",value)),
-            AddressingMode::AddrIndirectX => String::from(format!("(${:02X},X)",value)),
-            AddressingMode::AddrIndirectY => String::from(format!("(${:02X}),Y",value)),
-            AddressingMode::AddrRelative => String::from(format!("${:04X}",value)),
-            AddressingMode::AddrIndirect => String::from(format!("(${:04X})",value)),
+            AddressingMode::AddrImmediate => format!("#${:02X}",value),
+            AddressingMode::AddrZeroPage  => format!("${:02X}",value),
+            AddressingMode::AddrZeroPageX => format!("${:02X},X",value),
+            AddressingMode::AddrZeroPageY => format!("${:02X},Y",value),
+            AddressingMode::AddrAbsolute => format!("${:04X}",value),
+            AddressingMode::AddrAbsoluteX => format!("${:04X},X",value),
+            AddressingMode::AddrAbsoluteY => format!("${:04X},Y",value),
+            AddressingMode::AddrIndirectX => format!("(${:02X},X)",value),
+            AddressingMode::AddrIndirectY => format!("(${:02X}),Y",value),
+            AddressingMode::AddrRelative => format!("${:04X}",value),
+            AddressingMode::AddrIndirect => format!("(${:04X})",value),
+        }
+    }
+
+    // Solo la parte dell'indirizzo, senza indici o parentesi.
+    pub fn format_address(addressing:&AddressingMode, value: &u16) -> String {
+        match addressing {
+            AddressingMode::AddrZeroPage  |
+            AddressingMode::AddrZeroPageX |
+            AddressingMode::AddrZeroPageY |
+            AddressingMode::AddrIndirectX |
+            AddressingMode::AddrIndirectY => format!("${:02X}",value),
+            _ => format!("${:04X}",value),
         }
     }
 
@@ -1,6 +1,6 @@
 use crate::disassembler::{types::{DisassembledLine, LineType}, DisassemblerTrait, UNDOC_OPCODE};
 
-use super::{addressing::AddressingMode, opcodes6510::OPCODES_TABLE, Cpu6510};
+use super::{addressing::AddressingMode, opcodes6510::{Penalty, OPCODES_TABLE}, registers::registers, Cpu6510};
 
 
 impl DisassemblerTrait for Cpu6510 {
@@ -17,7 +17,7 @@ impl DisassemblerTrait for Cpu6510 {
         dasm_line.address = current_address;
         dasm_line.flags = opcode.flags;
 
-        if opcode.flags & UNDOC_OPCODE != 0 {
+        if opcode.flags & UNDOC_OPCODE != 0 && !self.undocumented_opcodes {
             dasm_line.opcode = String::from("???");
             // In questo caso è un istruzione non documentata
             // e potrebbe avere senso una LineType::ToBeExamine.
@@ -33,6 +33,8 @@ impl DisassemblerTrait for Cpu6510 {
 
         dasm_line.opcode = opcode.mnemonic.to_owned();
         dasm_line.byte_code[0] = opcode.opcode;
+        dasm_line.line_type = LineType::Instruction;
+        (dasm_line.regs_read, dasm_line.regs_written) = registers(opcode);
 
         if dasm_line.instr_size != 0 {
             if dasm_line.instr_size == 1 {
@@ -54,7 +56,7 @@ impl DisassemblerTrait for Cpu6510 {
 
                 dasm_line.operand.push_str(AddressingMode::format_string(&opcode.addressing, &address).as_str());
             }else if dasm_line.instr_size == 2 {
-                address = self.memory.read_word_le((self.pc+1) as u32) as u16;
+                address = self.memory.read_word_le((self.pc+1) as u32);
                 dasm_line.byte_code[1] = (address & 0x00FF) as u8;
                 dasm_line.byte_code[2] = ((address & 0xFF00) >> 8) as u8;
                 dasm_line.operand.push_str(AddressingMode::format_string(&opcode.addressing, &address).as_str());
@@ -64,9 +66,17 @@ impl DisassemblerTrait for Cpu6510 {
 
             if opcode.addressing != AddressingMode::AddrImmediate {
                 dasm_line.address_ref = address as u32;
+                dasm_line.address_text = AddressingMode::format_address(&opcode.addressing, &address);
             }
         }
 
+        dasm_line.cycles = opcode.cycles as u32;
+        dasm_line.extra_cycles = match opcode.penalty {
+            Penalty::None => 0,
+            Penalty::PageCross => 1,
+            Penalty::Branch => if (current_address + 2) & 0xFF00 != dasm_line.address_ref & 0xFF00 { 2 } else { 1 },
+        };
+
         self.pc += pc_inc as u16;
         Some(dasm_line)
     }
@@ -0,0 +1,478 @@
+use crate::{cpus::trace::Trace, memory::BinaryBuffer};
+use super::{addressing::AddressingMode, opcodes6510::{Penalty, OPCODES_TABLE}};
+
+const FLAG_C: u8 = 1;
+const FLAG_Z: u8 = 1 << 1;
+const FLAG_I: u8 = 1 << 2;
+const FLAG_D: u8 = 1 << 3;
+const FLAG_B: u8 = 1 << 4;
+const FLAG_U: u8 = 1 << 5;
+const FLAG_V: u8 = 1 << 6;
+const FLAG_N: u8 = 1 << 7;
+
+const STACK_PAGE: u16 = 0x0100;
+const MEMORY_SIZE: usize = 0x10000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StopReason {
+    CycleLimit,
+    Trap(u32),
+    // RTS o RTI con lo stack al livello dell'ingresso.
+    Return(u32),
+    Jam(u32),
+    Break(u32),
+    // Il PC è finito su memoria mai caricata né scritta.
+    Uninitialized(u32),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Operand {
+    Accumulator,
+    Immediate(u8),
+    Address(u16),
+}
+
+// Emulatore minimale del 6510 sulla stessa tabella del disassemblatore.
+// La memoria è una RAM piatta da 64K senza banking né I/O: basta a seguire
+// il flusso del codice e a registrare quali byte vengono eseguiti o letti.
+// Le JSR verso memoria non inizializzata (ad es. il KERNAL quando non è
+// nell'immagine) vengono saltate come se la routine ritornasse subito.
+pub struct Emulator6510 {
+    pub a: u8,
+    pub x: u8,
+    pub y: u8,
+    pub sp: u8,
+    pub p: u8,
+    pub pc: u16,
+    ram: Vec<u8>,
+    initialized: Vec<bool>,
+    entry_sp: u8,
+    cycles: u64,
+    trace: Trace
+}
+
+fn page_crossed(from: u16, to: u16) -> bool {
+    from & 0xFF00 != to & 0xFF00
+}
+
+impl Emulator6510 {
+    pub fn new(memory: &BinaryBuffer) -> Self {
+        // Un'immagine caricata oltre i 64K non ha byte da copiare nella RAM.
+        let start = (memory.get_loaded_address() as usize).min(MEMORY_SIZE);
+        let end = (start + memory.get_size()).min(MEMORY_SIZE);
+
+        let mut emulator = Emulator6510 {
+            a: 0,
+            x: 0,
+            y: 0,
+            sp: 0xFF,
+            p: FLAG_U | FLAG_I,
+            pc: start.min(MEMORY_SIZE - 1) as u16,
+            ram: vec![0; MEMORY_SIZE],
+            initialized: vec![false; MEMORY_SIZE],
+            entry_sp: 0xFF,
+            cycles: 0,
+            trace: Trace::default()
+        };
+
+        emulator.ram[start..end].copy_from_slice(&memory.get_data()[..end - start]);
+        emulator.initialized[start..end].fill(true);
+        emulator
+    }
+
+    pub fn get_cycles(&self) -> u64 {
+        self.cycles
+    }
+
+    pub fn get_trace(&self) -> &Trace {
+        &self.trace
+    }
+
+    pub fn read_memory(&self, address: u16) -> u8 {
+        self.ram[address as usize]
+    }
+
+    // Esegue da 'entry' finché la routine ritorna, si blocca, raggiunge
+    // una delle trappole o supera max_cycles cicli.
+    pub fn run(&mut self, entry: u32, max_cycles: u64, traps: &[u32]) -> StopReason {
+        let limit = self.cycles + max_cycles;
+        self.pc = entry as u16;
+        self.entry_sp = self.sp;
+
+        loop {
+            let pc = self.pc as u32;
+            if self.cycles >= limit {
+                return StopReason::CycleLimit;
+            }
+            if traps.contains(&pc) {
+                return StopReason::Trap(pc);
+            }
+            if !self.initialized[pc as usize] {
+                return StopReason::Uninitialized(pc);
+            }
+            if let Some(reason) = self.step() {
+                return reason;
+            }
+        }
+    }
+
+    fn read(&mut self, address: u16) -> u8 {
+        self.trace.record_read(address as u32);
+        self.ram[address as usize]
+    }
+
+    fn write(&mut self, address: u16, value: u8) {
+        self.trace.record_write(address as u32);
+        self.ram[address as usize] = value;
+        self.initialized[address as usize] = true;
+    }
+
+    fn fetch(&self, address: u16) -> u8 {
+        self.ram[address as usize]
+    }
+
+    fn fetch_word(&self, address: u16) -> u16 {
+        self.fetch(address) as u16 | (self.fetch(address.wrapping_add(1)) as u16) << 8
+    }
+
+    // Legge un puntatore in pagina zero, il byte alto non esce dalla pagina.
+    fn read_zero_page_word(&mut self, address: u8) -> u16 {
+        self.read(address as u16) as u16 | (self.read(address.wrapping_add(1) as u16) as u16) << 8
+    }
+
+    fn push(&mut self, value: u8) {
+        self.write(STACK_PAGE | self.sp as u16, value);
+        self.sp = self.sp.wrapping_sub(1);
+    }
+
+    fn pull(&mut self) -> u8 {
+        self.sp = self.sp.wrapping_add(1);
+        self.read(STACK_PAGE | self.sp as u16)
+    }
+
+    fn push_word(&mut self, value: u16) {
+        self.push((value >> 8) as u8);
+        self.push(value as u8);
+    }
+
+    fn pull_word(&mut self) -> u16 {
+        let lo = self.pull() as u16;
+        lo | (self.pull() as u16) << 8
+    }
+
+    fn set_flag(&mut self, flag: u8, value: bool) {
+        if value {
+            self.p |= flag;
+        }else{
+            self.p &= !flag;
+        }
+    }
+
+    fn flag(&self, flag: u8) -> bool {
+        self.p & flag != 0
+    }
+
+    fn set_nz(&mut self, value: u8) -> u8 {
+        self.set_flag(FLAG_Z, value == 0);
+        self.set_flag(FLAG_N, value & 0x80 != 0);
+        value
+    }
+
+    // Risolve l'operando, il secondo valore indica se l'indice ha
+    // attraversato una pagina.
+    fn operand(&mut self, addressing: &AddressingMode, pc: u16) -> (Operand, bool) {
+        let byte = self.fetch(pc.wrapping_add(1));
+        let word = self.fetch_word(pc.wrapping_add(1));
+
+        match addressing {
+            AddressingMode::AddrImplied |
+            AddressingMode::AddrAccumulator => (Operand::Accumulator, false),
+            AddressingMode::AddrImmediate => (Operand::Immediate(byte), false),
+            AddressingMode::AddrZeroPage => (Operand::Address(byte as u16), false),
+            AddressingMode::AddrZeroPageX => (Operand::Address(byte.wrapping_add(self.x) as u16), false),
+            AddressingMode::AddrZeroPageY => (Operand::Address(byte.wrapping_add(self.y) as u16), false),
+            AddressingMode::AddrAbsolute => (Operand::Address(word), false),
+            AddressingMode::AddrAbsoluteX => {
+                let address = word.wrapping_add(self.x as u16);
+                (Operand::Address(address), page_crossed(word, address))
+            },
+            AddressingMode::AddrAbsoluteY => {
+                let address = word.wrapping_add(self.y as u16);
+                (Operand::Address(address), page_crossed(word, address))
+            },
+            AddressingMode::AddrIndirectX => {
+                let pointer = byte.wrapping_add(self.x);
+                (Operand::Address(self.read_zero_page_word(pointer)), false)
+            },
+            AddressingMode::AddrIndirectY => {
+                let base = self.read_zero_page_word(byte);
+                let address = base.wrapping_add(self.y as u16);
+                (Operand::Address(address), page_crossed(base, address))
+            },
+            AddressingMode::AddrRelative => {
+                let next = pc.wrapping_add(2);
+                (Operand::Address(next.wrapping_add(byte as i8 as u16)), false)
+            },
+            // Il bug del 6502: il byte alto viene letto dalla stessa pagina.
+            AddressingMode::AddrIndirect => {
+                let lo = self.read(word) as u16;
+                let hi = self.read((word & 0xFF00) | (word.wrapping_add(1) & 0x00FF)) as u16;
+                (Operand::Address(lo | hi << 8), false)
+            },
+        }
+    }
+
+    fn load(&mut self, operand: Operand) -> u8 {
+        match operand {
+            Operand::Accumulator => self.a,
+            Operand::Immediate(value) => value,
+            Operand::Address(address) => self.read(address)
+        }
+    }
+
+    fn store(&mut self, operand: Operand, value: u8) {
+        match operand {
+            Operand::Accumulator => self.a = value,
+            Operand::Immediate(_) => {},
+            Operand::Address(address) => self.write(address, value)
+        }
+    }
+
+    fn address(operand: Operand) -> u16 {
+        match operand {
+            Operand::Address(address) => address,
+            _ => 0
+        }
+    }
+
+    // Valore delle istruzioni SHA/SHX/SHY/TAS: registro AND byte alto+1.
+    fn unstable_store(&mut self, operand: Operand, value: u8) {
+        let address = Self::address(operand);
+        self.store(operand, value & ((address >> 8) as u8).wrapping_add(1));
+    }
+
+    fn adc(&mut self, value: u8) {
+        let carry = self.flag(FLAG_C) as u16;
+        let binary = self.a as u16 + value as u16 + carry;
+
+        if self.flag(FLAG_D) {
+            let mut lo = (self.a & 0x0F) as u16 + (value & 0x0F) as u16 + carry;
+            let mut hi = (self.a >> 4) as u16 + (value >> 4) as u16;
+            if lo > 9 {
+                lo += 6;
+            }
+            if lo > 0x0F {
+                hi += 1;
+            }
+            self.set_flag(FLAG_Z, binary & 0xFF == 0);
+            self.set_flag(FLAG_N, hi & 0x08 != 0);
+            self.set_flag(FLAG_V, (self.a ^ (hi << 4) as u8) & !(self.a ^ value) & 0x80 != 0);
+            if hi > 9 {
+                hi += 6;
+            }
+            self.set_flag(FLAG_C, hi > 0x0F);
+            self.a = ((hi << 4) | (lo & 0x0F)) as u8;
+        }else{
+            let result = binary as u8;
+            self.set_flag(FLAG_C, binary > 0xFF);
+            self.set_flag(FLAG_V, (self.a ^ result) & (value ^ result) & 0x80 != 0);
+            self.a = self.set_nz(result);
+        }
+    }
+
+    fn sbc(&mut self, value: u8) {
+        let borrow = !self.flag(FLAG_C) as i16;
+        let binary = self.a as i16 - value as i16 - borrow;
+        let result = binary as u8;
+
+        // Sul 6502 NMOS i flag seguono sempre il risultato binario.
+        self.set_flag(FLAG_C, binary >= 0);
+        self.set_flag(FLAG_V, (self.a ^ value) & (self.a ^ result) & 0x80 != 0);
+        self.set_nz(result);
+
+        if self.flag(FLAG_D) {
+            let mut lo = (self.a & 0x0F) as i16 - (value & 0x0F) as i16 - borrow;
+            let mut hi = (self.a >> 4) as i16 - (value >> 4) as i16;
+            if lo < 0 {
+                lo -= 6;
+                hi -= 1;
+            }
+            if hi < 0 {
+                hi -= 6;
+            }
+            self.a = ((hi << 4) | (lo & 0x0F)) as u8;
+        }else{
+            self.a = result;
+        }
+    }
+
+    fn compare(&mut self, register: u8, value: u8) {
+        self.set_flag(FLAG_C, register >= value);
+        self.set_nz(register.wrapping_sub(value));
+    }
+
+    fn asl(&mut self, value: u8) -> u8 {
+        self.set_flag(FLAG_C, value & 0x80 != 0);
+        self.set_nz(value << 1)
+    }
+
+    fn lsr(&mut self, value: u8) -> u8 {
+        self.set_flag(FLAG_C, value & 0x01 != 0);
+        self.set_nz(value >> 1)
+    }
+
+    fn rol(&mut self, value: u8) -> u8 {
+        let carry = self.flag(FLAG_C) as u8;
+        self.set_flag(FLAG_C, value & 0x80 != 0);
+        self.set_nz(value << 1 | carry)
+    }
+
+    fn ror(&mut self, value: u8) -> u8 {
+        let carry = (self.flag(FLAG_C) as u8) << 7;
+        self.set_flag(FLAG_C, value & 0x01 != 0);
+        self.set_nz(value >> 1 | carry)
+    }
+
+    fn branch(&mut self, condition: bool, target: u16) {
+        if condition {
+            self.cycles += if page_crossed(self.pc, target) { 2 } else { 1 };
+            self.pc = target;
+        }
+    }
+
+    // Esegue un'istruzione, ritorna il motivo se l'esecuzione deve fermarsi.
+    pub fn step(&mut self) -> Option<StopReason> {
+        let pc = self.pc;
+        let opcode = &OPCODES_TABLE[self.fetch(pc) as usize];
+        let size = AddressingMode::get_pc_inc(&opcode.addressing) as u16;
+
+        self.trace.record_execution(pc as u32, size as u32);
+        self.pc = pc.wrapping_add(size);
+        self.cycles += opcode.cycles as u64;
+
+        let (operand, crossed) = self.operand(&opcode.addressing, pc);
+        if crossed && opcode.penalty == Penalty::PageCross {
+            self.cycles += 1;
+        }
+
+        match opcode.mnemonic {
+            "LDA" => { let v = self.load(operand); self.a = self.set_nz(v); },
+            "LDX" => { let v = self.load(operand); self.x = self.set_nz(v); },
+            "LDY" => { let v = self.load(operand); self.y = self.set_nz(v); },
+            "LAX" => { let v = self.load(operand); self.a = self.set_nz(v); self.x = v; },
+            "STA" => self.store(operand, self.a),
+            "STX" => self.store(operand, self.x),
+            "STY" => self.store(operand, self.y),
+            "SAX" => self.store(operand, self.a & self.x),
+            "ADC" => { let v = self.load(operand); self.adc(v); },
+            "SBC" => { let v = self.load(operand); self.sbc(v); },
+            "AND" => { let v = self.load(operand); self.a = self.set_nz(self.a & v); },
+            "ORA" => { let v = self.load(operand); self.a = self.set_nz(self.a | v); },
+            "EOR" => { let v = self.load(operand); self.a = self.set_nz(self.a ^ v); },
+            "CMP" => { let v = self.load(operand); self.compare(self.a, v); },
+            "CPX" => { let v = self.load(operand); self.compare(self.x, v); },
+            "CPY" => { let v = self.load(operand); self.compare(self.y, v); },
+            "BIT" => {
+                let v = self.load(operand);
+                self.set_flag(FLAG_Z, self.a & v == 0);
+                self.set_flag(FLAG_N, v & 0x80 != 0);
+                self.set_flag(FLAG_V, v & 0x40 != 0);
+            },
+            "ASL" => { let v = self.load(operand); let r = self.asl(v); self.store(operand, r); },
+            "LSR" => { let v = self.load(operand); let r = self.lsr(v); self.store(operand, r); },
+            "ROL" => { let v = self.load(operand); let r = self.rol(v); self.store(operand, r); },
+            "ROR" => { let v = self.load(operand); let r = self.ror(v); self.store(operand, r); },
+            "INC" => { let v = self.load(operand); let r = self.set_nz(v.wrapping_add(1)); self.store(operand, r); },
+            "DEC" => { let v = self.load(operand); let r = self.set_nz(v.wrapping_sub(1)); self.store(operand, r); },
+            "SLO" => { let v = self.load(operand); let r = self.asl(v); self.store(operand, r); self.a = self.set_nz(self.a | r); },
+            "RLA" => { let v = self.load(operand); let r = self.rol(v); self.store(operand, r); self.a = self.set_nz(self.a & r); },
+            "SRE" => { let v = self.load(operand); let r = self.lsr(v); self.store(operand, r); self.a = self.set_nz(self.a ^ r); },
+            "RRA" => { let v = self.load(operand); let r = self.ror(v); self.store(operand, r); self.adc(r); },
+            "DCP" => { let v = self.load(operand); let r = v.wrapping_sub(1); self.store(operand, r); self.compare(self.a, r); },
+            "ISC" => { let v = self.load(operand); let r = v.wrapping_add(1); self.store(operand, r); self.sbc(r); },
+            "ANC" => {
+                let v = self.load(operand);
+                self.a = self.set_nz(self.a & v);
+                self.set_flag(FLAG_C, self.a & 0x80 != 0);
+            },
+            "ALR" => { let v = self.load(operand); self.a = self.lsr(self.a & v); },
+            "ARR" => {
+                let v = self.load(operand);
+                let r = self.set_nz((self.a & v) >> 1 | (self.flag(FLAG_C) as u8) << 7);
+                self.set_flag(FLAG_C, r & 0x40 != 0);
+                self.set_flag(FLAG_V, (r >> 6 ^ r >> 5) & 1 != 0);
+                self.a = r;
+            },
+            "SBX" => {
+                let v = self.load(operand);
+                let ax = self.a & self.x;
+                self.set_flag(FLAG_C, ax >= v);
+                self.x = self.set_nz(ax.wrapping_sub(v));
+            },
+            "ANE" => { let v = self.load(operand); self.a = self.set_nz((self.a | 0xEE) & self.x & v); },
+            "LXA" => { let v = self.load(operand); self.a = self.set_nz((self.a | 0xEE) & v); self.x = self.a; },
+            "LAS" => { let v = self.load(operand) & self.sp; self.a = self.set_nz(v); self.x = v; self.sp = v; },
+            "SHA" => self.unstable_store(operand, self.a & self.x),
+            "SHX" => self.unstable_store(operand, self.x),
+            "SHY" => self.unstable_store(operand, self.y),
+            "TAS" => { self.sp = self.a & self.x; self.unstable_store(operand, self.sp); },
+            "BPL" => self.branch(!self.flag(FLAG_N), Self::address(operand)),
+            "BMI" => self.branch(self.flag(FLAG_N), Self::address(operand)),
+            "BVC" => self.branch(!self.flag(FLAG_V), Self::address(operand)),
+            "BVS" => self.branch(self.flag(FLAG_V), Self::address(operand)),
+            "BCC" => self.branch(!self.flag(FLAG_C), Self::address(operand)),
+            "BCS" => self.branch(self.flag(FLAG_C), Self::address(operand)),
+            "BNE" => self.branch(!self.flag(FLAG_Z), Self::address(operand)),
+            "BEQ" => self.branch(self.flag(FLAG_Z), Self::address(operand)),
+            "JMP" => self.pc = Self::address(operand),
+            "JSR" => {
+                let target = Self::address(operand);
+                if self.initialized[target as usize] {
+                    self.push_word(self.pc.wrapping_sub(1));
+                    self.pc = target;
+                }
+            },
+            "RTS" => {
+                if self.sp == self.entry_sp {
+                    return Some(StopReason::Return(pc as u32));
+                }
+                self.pc = self.pull_word().wrapping_add(1);
+            },
+            "RTI" => {
+                if self.sp == self.entry_sp {
+                    return Some(StopReason::Return(pc as u32));
+                }
+                self.p = self.pull() | FLAG_U;
+                self.pc = self.pull_word();
+            },
+            "BRK" => return Some(StopReason::Break(pc as u32)),
+            "JAM" => return Some(StopReason::Jam(pc as u32)),
+            "PHA" => self.push(self.a),
+            "PHP" => self.push(self.p | FLAG_B | FLAG_U),
+            "PLA" => { let v = self.pull(); self.a = self.set_nz(v); },
+            "PLP" => self.p = self.pull() | FLAG_U,
+            "TAX" => self.x = self.set_nz(self.a),
+            "TAY" => self.y = self.set_nz(self.a),
+            "TXA" => self.a = self.set_nz(self.x),
+            "TYA" => self.a = self.set_nz(self.y),
+            "TSX" => self.x = self.set_nz(self.sp),
+            "TXS" => self.sp = self.x,
+            "INX" => self.x = self.set_nz(self.x.wrapping_add(1)),
+            "INY" => self.y = self.set_nz(self.y.wrapping_add(1)),
+            "DEX" => self.x = self.set_nz(self.x.wrapping_sub(1)),
+            "DEY" => self.y = self.set_nz(self.y.wrapping_sub(1)),
+            "CLC" => self.set_flag(FLAG_C, false),
+            "SEC" => self.set_flag(FLAG_C, true),
+            "CLI" => self.set_flag(FLAG_I, false),
+            "SEI" => self.set_flag(FLAG_I, true),
+            "CLD" => self.set_flag(FLAG_D, false),
+            "SED" => self.set_flag(FLAG_D, true),
+            "CLV" => self.set_flag(FLAG_V, false),
+            // I NOP non documentati leggono comunque l'operando.
+            "NOP" => { self.load(operand); },
+            _ => {}
+        }
+
+        None
+    }
+}
@@ -0,0 +1,73 @@
+use super::{addressing::AddressingMode, opcodes6510::Opcode6510};
+
+pub const REG_A:u32 = 1;
+pub const REG_X:u32 = 1 << 1;
+pub const REG_Y:u32 = 1 << 2;
+pub const REG_SP:u32 = 1 << 3;
+pub const REG_P:u32 = 1 << 4;
+
+const REGISTER_NAMES: &[(u32, &str)] = &[(REG_A, "A"), (REG_X, "X"), (REG_Y, "Y"), (REG_SP, "SP"), (REG_P, "P")];
+
+pub fn register_names(mask: u32) -> Vec<&'static str> {
+    REGISTER_NAMES.iter().filter(|(reg, _)| mask & reg != 0).map(|(_, name)| *name).collect()
+}
+
+// Registri letti e scritti dall'istruzione, compresi gli indici usati
+// dal modo di indirizzamento.
+pub fn registers(opcode: &Opcode6510) -> (u32, u32) {
+    let (mut read, mut written) = match opcode.mnemonic {
+        "ADC" | "SBC" | "RLA" | "RRA" | "ISC" | "ARR" => (REG_A|REG_P, REG_A|REG_P),
+        "AND" | "ORA" | "EOR" | "SLO" | "SRE" | "ANC" | "ALR" => (REG_A, REG_A|REG_P),
+        "ASL" | "LSR" => (0, REG_P),
+        "ROL" | "ROR" => (REG_P, REG_P),
+        "BPL" | "BMI" | "BVC" | "BVS" | "BCC" | "BCS" | "BNE" | "BEQ" => (REG_P, 0),
+        "BIT" | "CMP" | "DCP" => (REG_A, REG_P),
+        "CPX" => (REG_X, REG_P),
+        "CPY" => (REG_Y, REG_P),
+        "BRK" => (REG_P|REG_SP, REG_P|REG_SP),
+        "CLC" | "CLD" | "CLI" | "CLV" | "SEC" | "SED" | "SEI" | "INC" | "DEC" => (0, REG_P),
+        "INX" | "DEX" => (REG_X, REG_X|REG_P),
+        "INY" | "DEY" => (REG_Y, REG_Y|REG_P),
+        "LDA" => (0, REG_A|REG_P),
+        "LDX" => (0, REG_X|REG_P),
+        "LDY" => (0, REG_Y|REG_P),
+        "LAX" => (0, REG_A|REG_X|REG_P),
+        "LXA" => (REG_A, REG_A|REG_X|REG_P),
+        "ANE" => (REG_A|REG_X, REG_A|REG_P),
+        "SBX" => (REG_A|REG_X, REG_X|REG_P),
+        "LAS" => (REG_SP, REG_A|REG_X|REG_SP|REG_P),
+        "STA" => (REG_A, 0),
+        "STX" | "SHX" => (REG_X, 0),
+        "STY" | "SHY" => (REG_Y, 0),
+        "SAX" | "SHA" => (REG_A|REG_X, 0),
+        "TAS" => (REG_A|REG_X, REG_SP),
+        "TAX" => (REG_A, REG_X|REG_P),
+        "TAY" => (REG_A, REG_Y|REG_P),
+        "TXA" => (REG_X, REG_A|REG_P),
+        "TYA" => (REG_Y, REG_A|REG_P),
+        "TSX" => (REG_SP, REG_X|REG_P),
+        "TXS" => (REG_X, REG_SP),
+        "PHA" => (REG_A|REG_SP, REG_SP),
+        "PHP" => (REG_P|REG_SP, REG_SP),
+        "PLA" => (REG_SP, REG_A|REG_SP|REG_P),
+        "PLP" | "RTI" => (REG_SP, REG_SP|REG_P),
+        "JSR" | "RTS" => (REG_SP, REG_SP),
+        _ => (0, 0)
+    };
+
+    match opcode.addressing {
+        AddressingMode::AddrAccumulator => {
+            read |= REG_A;
+            written |= REG_A;
+        },
+        AddressingMode::AddrZeroPageX |
+        AddressingMode::AddrAbsoluteX |
+        AddressingMode::AddrIndirectX => read |= REG_X,
+        AddressingMode::AddrZeroPageY |
+        AddressingMode::AddrAbsoluteY |
+        AddressingMode::AddrIndirectY => read |= REG_Y,
+        _ => {}
+    }
+
+    (read, written)
+}
@@ -0,0 +1,238 @@
+use crate::{cpus::{opcode_reference::{check_table, parse_reference, TableEntry}, CpuTrait}, disassembler::{types::{LineType, MemoryAccess, OpcodeStability}, DisassemblerTrait}, memory::BinaryBuffer};
+use crate::disassembler::{BRANCH_OPCODE, INDIRECT_FLAG, JAM_OPCODE, JMP_OPCODE, LOAD_FLAG, RETURN_OPCODE, STORE_FLAG, SUBROUTINE_OPCODE, UNDOC_OPCODE, UNSTABLE_OPCODE, VALID_OPCODE};
+
+use super::{addressing::AddressingMode, emulator::{Emulator6510, StopReason}, opcodes6510::{Opcode6510, Penalty, OPCODES_TABLE}, registers::{register_names, registers, REG_A, REG_P, REG_SP, REG_X, REG_Y}, Cpu6510};
+
+const NMOS6502_REFERENCE: &str = include_str!("nmos6502.ref");
+
+// Flag di OPCODES_TABLE confrontati con il riferimento.
+const CHECKED_FLAGS: &[(&str, u32)] = &[
+    ("VALID", VALID_OPCODE),
+    ("UNDOC", UNDOC_OPCODE),
+    ("BRANCH", BRANCH_OPCODE),
+    ("SUBROUTINE", SUBROUTINE_OPCODE),
+    ("JMP", JMP_OPCODE),
+    ("INDIRECT", INDIRECT_FLAG),
+    ("RETURN", RETURN_OPCODE),
+    ("UNSTABLE", UNSTABLE_OPCODE),
+    ("JAM", JAM_OPCODE),
+    ("LOAD", LOAD_FLAG),
+    ("STORE", STORE_FLAG),
+];
+
+impl TableEntry for Opcode6510 {
+    fn opcode(&self) -> u32 {
+        self.opcode as u32
+    }
+    fn mnemonic(&self) -> &str {
+        self.mnemonic
+    }
+    fn mode_name(&self) -> &str {
+        match self.addressing {
+            AddressingMode::AddrImplied => "imp",
+            AddressingMode::AddrAccumulator => "acc",
+            AddressingMode::AddrImmediate => "imm",
+            AddressingMode::AddrZeroPage => "zp",
+            AddressingMode::AddrZeroPageX => "zpx",
+            AddressingMode::AddrZeroPageY => "zpy",
+            AddressingMode::AddrAbsolute => "abs",
+            AddressingMode::AddrAbsoluteX => "abx",
+            AddressingMode::AddrAbsoluteY => "aby",
+            AddressingMode::AddrIndirectX => "izx",
+            AddressingMode::AddrIndirectY => "izy",
+            AddressingMode::AddrRelative => "rel",
+            AddressingMode::AddrIndirect => "ind",
+        }
+    }
+    fn length(&self) -> u8 {
+        AddressingMode::get_pc_inc(&self.addressing)
+    }
+    fn flags(&self) -> u32 {
+        self.flags
+    }
+    fn timing(&self) -> String {
+        let suffix = match self.penalty {
+            Penalty::None => "",
+            Penalty::PageCross => "*",
+            Penalty::Branch => "**",
+        };
+        format!("{}{}", self.cycles, suffix)
+    }
+}
+
+#[test]
+fn reference_covers_every_opcode() {
+    let reference = parse_reference(NMOS6502_REFERENCE);
+    assert_eq!(reference.len(), 256);
+    for (index, entry) in reference.iter().enumerate() {
+        assert_eq!(entry.opcode, index as u32);
+    }
+}
+
+#[test]
+fn opcode_table_matches_reference() {
+    let reference = parse_reference(NMOS6502_REFERENCE);
+    let errors = check_table(OPCODES_TABLE, &reference, CHECKED_FLAGS);
+    assert!(errors.is_empty(), "OPCODES_TABLE mismatches:\n{}", errors.join("\n"));
+}
+
+fn cpu_with(bytes: &[u8]) -> Cpu6510 {
+    let mut cpu = Cpu6510::new(BinaryBuffer::new(bytes.to_vec(), 0xC000));
+    cpu.set_pc(0);
+    cpu
+}
+
+#[test]
+fn undocumented_opcodes_are_unknown_by_default() {
+    let mut cpu = cpu_with(&[0x07, 0xFB, 0xEA]);
+
+    let line = cpu.disassemble_next().unwrap();
+    assert_eq!(line.line_type, LineType::UnknownInstruction);
+    assert_eq!(line.opcode, "???");
+    assert_eq!(line.instr_size, 0);
+
+    let line = cpu.disassemble_next().unwrap();
+    assert_eq!(line.address, 0xC001);
+}
+
+#[test]
+fn undocumented_opcodes_decode_when_enabled() {
+    let mut cpu = cpu_with(&[0x07, 0xFB, 0xAF, 0x00, 0xC0, 0xEA]);
+    cpu.set_undocumented_opcodes(true);
+
+    let line = cpu.disassemble_next().unwrap();
+    assert_eq!(line.line_type, LineType::Instruction);
+    assert_eq!(line.opcode, "SLO");
+    assert_eq!(line.operand, "$FB");
+    assert_eq!(line.address_ref, 0xFB);
+
+    let line = cpu.disassemble_next().unwrap();
+    assert_eq!(line.opcode, "LAX");
+    assert_eq!(line.operand, "$C000");
+    assert_eq!(line.stability(), OpcodeStability::Stable);
+
+    let line = cpu.disassemble_next().unwrap();
+    assert_eq!(line.address, 0xC005);
+    assert_eq!(line.opcode, "NOP");
+}
+
+#[test]
+fn stability_classification() {
+    let mut cpu = cpu_with(&[0x8B, 0x00, 0x02, 0x0B, 0x01]);
+    cpu.set_undocumented_opcodes(true);
+
+    assert_eq!(cpu.disassemble_next().unwrap().stability(), OpcodeStability::Unstable);
+    assert_eq!(cpu.disassemble_next().unwrap().stability(), OpcodeStability::Jam);
+    assert_eq!(cpu.disassemble_next().unwrap().stability(), OpcodeStability::Stable);
+}
+
+#[test]
+fn every_opcode_has_register_usage() {
+    for opcode in OPCODES_TABLE {
+        let (read, written) = registers(opcode);
+        if read == 0 && written == 0 {
+            assert!(["NOP", "JMP", "JAM"].contains(&opcode.mnemonic), "${:02X} {} has no register usage", opcode.opcode, opcode.mnemonic);
+        }
+    }
+}
+
+#[test]
+fn memory_access_and_registers_reach_the_line() {
+    // STA $D020,X / INC $FB / LDA ($FB),Y / PHA
+    let mut cpu = cpu_with(&[0x9D, 0x20, 0xD0, 0xE6, 0xFB, 0xB1, 0xFB, 0x48]);
+
+    let line = cpu.disassemble_next().unwrap();
+    assert_eq!(line.memory_access(), MemoryAccess::Write);
+    assert_eq!(line.regs_read, REG_A|REG_X);
+    assert_eq!(line.regs_written, 0);
+
+    let line = cpu.disassemble_next().unwrap();
+    assert_eq!(line.memory_access(), MemoryAccess::ReadModifyWrite);
+    assert_eq!(line.regs_written, REG_P);
+
+    let line = cpu.disassemble_next().unwrap();
+    assert_eq!(line.memory_access(), MemoryAccess::Read);
+    assert_eq!(register_names(line.regs_read), vec!["Y"]);
+    assert_eq!(register_names(line.regs_written), vec!["A", "P"]);
+
+    let line = cpu.disassemble_next().unwrap();
+    assert_eq!(line.memory_access(), MemoryAccess::None);
+    assert_eq!(line.regs_read, REG_A|REG_SP);
+    assert_eq!(line.regs_written & REG_Y, 0);
+}
+
+fn emulator_with(bytes: &[u8]) -> Emulator6510 {
+    Emulator6510::new(&BinaryBuffer::new(bytes.to_vec(), 0xC000))
+}
+
+#[test]
+fn emulator_runs_until_return() {
+    // LDX #$03 / DEX / BNE -3 / RTS
+    let mut emulator = emulator_with(&[0xA2, 0x03, 0xCA, 0xD0, 0xFD, 0x60]);
+
+    assert_eq!(emulator.run(0xC000, 1000, &[]), StopReason::Return(0xC005));
+    assert_eq!(emulator.x, 0);
+    assert_eq!(emulator.get_cycles(), 2 + 2 * (2 + 3) + (2 + 2) + 6);
+
+    let trace = emulator.get_trace();
+    assert_eq!(trace.get_executed().collect::<Vec<u32>>(), vec![0xC000, 0xC002, 0xC003, 0xC005]);
+    assert!(trace.is_code_byte(0xC004));
+}
+
+#[test]
+fn emulator_ignores_image_beyond_64k() {
+    let mut emulator = Emulator6510::new(&BinaryBuffer::new(vec![0xEA; 4], 0x10010));
+    assert_eq!(emulator.pc, 0xFFFF);
+    assert_eq!(emulator.run(0x0000, 10, &[]), StopReason::Uninitialized(0x0000));
+
+    let emulator = Emulator6510::new(&BinaryBuffer::new(vec![0xEA; 4], 0xFFFE));
+    assert_eq!((emulator.read_memory(0xFFFE), emulator.read_memory(0xFFFF)), (0xEA, 0xEA));
+}
+
+#[test]
+fn emulator_records_memory_accesses() {
+    let bytes = [
+        0xF8,                   // C000 SED
+        0x18,                   // C001 CLC
+        0xAD, 0x10, 0xC0,       // C002 LDA $C010
+        0x69, 0x01,             // C005 ADC #$01
+        0x8D, 0x11, 0xC0,       // C007 STA $C011
+        0x20, 0xD2, 0xFF,       // C00A JSR $FFD2, fuori dall'immagine
+        0x02,                   // C00D JAM
+        0x00, 0x00,
+        0x19, 0x00,             // C010 dati
+    ];
+    let mut emulator = emulator_with(&bytes);
+
+    assert_eq!(emulator.run(0xC000, 1000, &[]), StopReason::Jam(0xC00D));
+    assert_eq!(emulator.read_memory(0xC011), 0x20);
+    assert!(emulator.get_trace().get_reads().contains(&0xC010));
+    assert!(emulator.get_trace().get_writes().contains(&0xC011));
+    assert!(!emulator.get_trace().is_executed(0xFFD2));
+
+    let mut emulator = emulator_with(&bytes);
+    assert_eq!(emulator.run(0xC000, 1000, &[0xC00A]), StopReason::Trap(0xC00A));
+    assert_eq!(emulator.run(0xC000, 3, &[]), StopReason::CycleLimit);
+}
+
+#[test]
+fn emulator_follows_stack_and_indirect_jumps() {
+    let bytes = [
+        0xA9, 0xC0,             // C000 LDA #$C0
+        0x48,                   // C002 PHA
+        0xA9, 0x08,             // C003 LDA #$08
+        0x48,                   // C005 PHA
+        0x60,                   // C006 RTS -> C009
+        0x00,
+        0x00,
+        0x6C, 0xFF, 0xC0,       // C009 JMP ($C0FF): il byte alto viene da $C000
+    ];
+    let mut bytes = bytes.to_vec();
+    bytes.resize(0x100, 0);
+    bytes[0xFF] = 0x40;
+
+    // $C000 contiene $A9: la destinazione è $A940, non inizializzata.
+    let mut emulator = emulator_with(&bytes);
+    assert_eq!(emulator.run(0xC000, 1000, &[]), StopReason::Uninitialized(0xA940));
+    assert!(emulator.get_trace().is_executed(0xC009));
+}
@@ -0,0 +1,109 @@
+// Harness per validare una tabella di opcode contro un dataset di riferimento.
+// Ogni CPU implementa TableEntry per il proprio tipo di opcode e fornisce
+// un file di riferimento con una riga per opcode:
+//
+//   <opcode hex>  <mnemonic>  <mode>  <length>  <flag>|<flag>...  <cycles>
+//
+// I cicli usano la notazione classica: "4*" aggiunge un ciclo se si
+// attraversa una pagina, "2**" è un branch.
+//
+// Il riferimento descrive il processore reale e non va adattato alla
+// tabella: ogni CPU indica quali flag della tabella vengono confrontati,
+// quelli che la tabella non modella ancora sono ignorati.
+//
+// Le righe vuote e quelle che iniziano con '#' vengono ignorate.
+
+const FLAG_NAMES: &[&str] = &["VALID", "UNDOC", "BRANCH", "SUBROUTINE", "JMP", "INDIRECT", "RETURN", "JAM", "UNSTABLE", "LOAD", "STORE"];
+
+pub trait TableEntry {
+    fn opcode(&self) -> u32;
+    fn mnemonic(&self) -> &str;
+    fn mode_name(&self) -> &str;
+    fn length(&self) -> u8;
+    fn flags(&self) -> u32;
+    fn timing(&self) -> String;
+}
+
+#[derive(Debug)]
+pub struct ReferenceEntry {
+    pub opcode: u32,
+    pub mnemonic: String,
+    pub mode: String,
+    pub length: u8,
+    pub flags: Vec<String>,
+    pub timing: String
+}
+
+pub fn parse_reference(text: &str) -> Vec<ReferenceEntry> {
+    let mut entries = Vec::new();
+
+    for (line_no, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        assert!(fields.len() == 6, "Malformed reference line {}: {}", line_no+1, line);
+        let flags: Vec<String> = fields[4].split('|').map(str::to_owned).collect();
+        for flag in &flags {
+            assert!(FLAG_NAMES.contains(&flag.as_str()), "Unknown flag in reference line {}: {}", line_no+1, flag);
+        }
+
+        entries.push(ReferenceEntry {
+            opcode: u32::from_str_radix(fields[0], 16).unwrap(),
+            mnemonic: fields[1].to_owned(),
+            mode: fields[2].to_owned(),
+            length: fields[3].parse().unwrap(),
+            flags,
+            timing: fields[5].to_owned()
+        });
+    }
+
+    entries
+}
+
+// Ritorna la lista delle differenze trovate, vuota se la tabella è conforme.
+// checked_flags associa i nomi del riferimento ai bit della tabella.
+pub fn check_table<T: TableEntry>(table: &[T], reference: &[ReferenceEntry], checked_flags: &[(&str, u32)]) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    if table.len() != reference.len() {
+        errors.push(format!("Table has {} entries, reference has {}", table.len(), reference.len()));
+    }
+
+    for (index, entry) in table.iter().enumerate() {
+        if entry.opcode() != index as u32 {
+            errors.push(format!("Entry at index ${:02X} declares opcode ${:02X}", index, entry.opcode()));
+        }
+    }
+
+    for expected in reference {
+        let Some(entry) = table.get(expected.opcode as usize) else {
+            errors.push(format!("${:02X}: missing from table", expected.opcode));
+            continue;
+        };
+
+        if entry.mnemonic() != expected.mnemonic {
+            errors.push(format!("${:02X}: mnemonic {} expected {}", expected.opcode, entry.mnemonic(), expected.mnemonic));
+        }
+        if entry.mode_name() != expected.mode {
+            errors.push(format!("${:02X} {}: mode {} expected {}", expected.opcode, expected.mnemonic, entry.mode_name(), expected.mode));
+        }
+        if entry.length() != expected.length {
+            errors.push(format!("${:02X} {}: length {} expected {}", expected.opcode, expected.mnemonic, entry.length(), expected.length));
+        }
+        for (name, flag) in checked_flags {
+            let in_table = entry.flags() & flag != 0;
+            if in_table != expected.flags.iter().any(|f| f == name) {
+                let state = if in_table { "set" } else { "missing" };
+                errors.push(format!("${:02X} {}: flag {} {}", expected.opcode, expected.mnemonic, name, state));
+            }
+        }
+        if entry.timing() != expected.timing {
+            errors.push(format!("${:02X} {}: cycles {} expected {}", expected.opcode, expected.mnemonic, entry.timing(), expected.timing));
+        }
+    }
+
+    errors
+}
@@ -0,0 +1,54 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+// Copertura raccolta eseguendo il codice: le istruzioni eseguite con la
+// loro lunghezza e gli indirizzi letti o scritti come dati.
+#[derive(Debug, Default, Clone)]
+pub struct Trace {
+    executed: BTreeMap<u32, u32>,
+    reads: BTreeSet<u32>,
+    writes: BTreeSet<u32>
+}
+
+impl Trace {
+    pub fn record_execution(&mut self, address: u32, size: u32) {
+        self.executed.insert(address, size);
+    }
+
+    pub fn record_read(&mut self, address: u32) {
+        self.reads.insert(address);
+    }
+
+    pub fn record_write(&mut self, address: u32) {
+        self.writes.insert(address);
+    }
+
+    pub fn is_executed(&self, address: u32) -> bool {
+        self.executed.contains_key(&address)
+    }
+
+    // Vero se il byte fa parte di un'istruzione eseguita, operandi compresi.
+    pub fn is_code_byte(&self, address: u32) -> bool {
+        self.executed.range(..=address).next_back().is_some_and(|(start, size)| address < start + size)
+    }
+
+    // Prima istruzione eseguita che parte dopo 'address'.
+    pub fn next_executed(&self, address: u32) -> Option<u32> {
+        self.executed.range(address + 1..).next().map(|(start, _)| *start)
+    }
+
+    pub fn is_accessed(&self, address: u32) -> bool {
+        self.reads.contains(&address) || self.writes.contains(&address)
+    }
+
+    pub fn get_executed(&self) -> impl Iterator<Item = u32> + '_ {
+        self.executed.keys().copied()
+    }
+
+    pub fn get_reads(&self) -> &BTreeSet<u32> {
+        &self.reads
+    }
+
+    pub fn get_writes(&self) -> &BTreeSet<u32> {
+        &self.writes
+    }
+}
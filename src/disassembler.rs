@@ -1,10 +1,37 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
 use types::DisassembledLine;
-use crate::cpus::CpuTrait;
+use regions::DataRegion;
+use project::BankEntry;
+use xref::Xref;
+use cfg::ControlFlowGraph;
+use zeropage::ZeroPageUsage;
+use classify::Classification;
+use interrupts::InterruptHandler;
+use crate::{cpus::{trace::Trace, CpuTrait}, platforms::{IoRegister, Platform, PlatformSymbol}};
 
 pub mod types;
+pub mod regions;
+pub mod project;
+pub mod symbols;
+pub mod xref;
+pub mod cfg;
+pub mod graphs;
+mod dispatch;
+mod entry;
+pub mod interrupts;
+pub mod coverage;
+mod smc;
+mod operands;
+pub mod json;
+mod html;
+pub mod zeropage;
+mod strings;
+mod hardware;
+pub mod classify;
 mod dasm;
+#[cfg(test)]
+mod tests;
 
 pub const UNINITIALIZED_OPCODE:u32 = 0;
 pub const VALID_OPCODE:u32 = 1;
@@ -17,15 +44,50 @@ pub const DATA_FLAG:u32 = 1 << 7;
 pub const LOAD_FLAG:u32 = 1 << 8;
 pub const STORE_FLAG:u32 = 1 << 9;
 pub const INDIRECT_FLAG:u32 = 1 << 10;
+pub const UNSTABLE_OPCODE:u32 = 1 << 11;
+pub const JAM_OPCODE:u32 = 1 << 12;
+pub const RETURN_OPCODE:u32 = 1 << 13;
+// Istruzione il cui codice viene modificato da una store del programma.
+pub const SELF_MODIFIED_FLAG:u32 = 1 << 14;
 
 pub struct Dasm {
     cpu: Box<dyn CpuTrait>,
     labels_map: HashMap<u32, String>,
+    user_labels: HashMap<u32, String>,
+    user_comments: HashMap<u32, String>,
+    platform: Option<Platform>,
+    platform_symbols: HashMap<u32, &'static PlatformSymbol>,
+    io_registers: HashMap<u32, &'static IoRegister>,
     lines: Vec<DisassembledLine>,
+    xrefs: BTreeMap<u32, Vec<Xref>>,
+    cfg: ControlFlowGraph,
     relative_label_id: u32,
     subroutine_label_id: u32,
     jump_label_id: u32,
-    start_pc:u32
+    pointer_label_id: u32,
+    patch_label_id: u32,
+    data_label_id: u32,
+    start_pc:u32,
+    // Regioni e punti di ingresso dichiarati dall'utente restano separati
+    // da quelli effettivi, ricostruiti a ogni disassemble().
+    user_entry_points: Vec<u32>,
+    entry_points: Vec<u32>,
+    user_regions: Vec<DataRegion>,
+    regions: Vec<DataRegion>,
+    // Classificazioni accettate, riapplicate a ogni disassemble().
+    classifications: Vec<Classification>,
+    // Con i vettori attivi disassemble() li legge come punti di ingresso.
+    vector_seeding: bool,
+    // Inizi delle istruzioni raggiunte seguendo il flusso dai punti di
+    // ingresso, vuoto se il disassemblaggio è lineare.
+    reached: BTreeSet<u32>,
+    banking: Vec<BankEntry>,
+    coverage: Option<Trace>,
+    zero_page: Vec<ZeroPageUsage>,
+    interrupt_handlers: Vec<InterruptHandler>,
+    // Lunghezza minima delle stringhe da cercare, None per non cercarle.
+    string_min_chars: Option<usize>,
+    warnings: Vec<String>
 }
 
 pub trait DisassemblerTrait {
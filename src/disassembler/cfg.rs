@@ -0,0 +1,257 @@
+use std::{collections::{BTreeMap, BTreeSet, VecDeque}, fmt};
+
+use super::{types::{DisassembledLine, LineType}, Dasm, BRANCH_OPCODE, INDIRECT_FLAG, JAM_OPCODE, JMP_OPCODE, RETURN_OPCODE, SUBROUTINE_OPCODE};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EdgeKind {
+    FallThrough,
+    Taken,
+    Call,
+    Return,
+}
+
+impl fmt::Display for EdgeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            EdgeKind::FallThrough => "fall-through",
+            EdgeKind::Taken => "taken",
+            EdgeKind::Call => "call",
+            EdgeKind::Return => "return",
+        };
+        f.write_str(name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Edge {
+    pub from: u32,
+    pub to: u32,
+    pub kind: EdgeKind
+}
+
+// Sequenza di istruzioni con un solo ingresso e una sola uscita.
+// first_line e last_line sono indici in Dasm::get_lines().
+#[derive(Debug, Clone)]
+pub struct BasicBlock {
+    pub start: u32,
+    pub end: u32,
+    pub first_line: usize,
+    pub last_line: usize
+}
+
+impl BasicBlock {
+    pub fn contains(&self, address: u32) -> bool {
+        address >= self.start && address <= self.end
+    }
+}
+
+// Blocchi raggiungibili da una radice senza seguire chiamate e ritorni.
+#[derive(Debug, Clone)]
+pub struct Function {
+    pub entry: u32,
+    pub blocks: BTreeSet<u32>
+}
+
+#[derive(Debug, Default)]
+pub struct ControlFlowGraph {
+    blocks: BTreeMap<u32, BasicBlock>,
+    edges: Vec<Edge>,
+    functions: BTreeMap<u32, Function>
+}
+
+fn is_code(line: &DisassembledLine) -> bool {
+    line.line_type == LineType::Instruction
+}
+
+pub(super) fn has_target(line: &DisassembledLine) -> bool {
+    line.flags & (BRANCH_OPCODE | SUBROUTINE_OPCODE) != 0 || (line.flags & JMP_OPCODE != 0 && line.flags & INDIRECT_FLAG == 0)
+}
+
+// BRK salta al vettore di interrupt e di norma è seguito da padding o dati.
+const BRK: u8 = 0x00;
+
+fn is_brk(line: &DisassembledLine) -> bool {
+    is_code(line) && line.byte_code[0] == BRK
+}
+
+fn ends_block(line: &DisassembledLine) -> bool {
+    line.flags & (BRANCH_OPCODE | SUBROUTINE_OPCODE | JMP_OPCODE | RETURN_OPCODE | JAM_OPCODE) != 0 || is_brk(line)
+}
+
+pub(super) fn falls_through(line: &DisassembledLine) -> bool {
+    line.flags & (JMP_OPCODE | RETURN_OPCODE | JAM_OPCODE) == 0 && !is_brk(line)
+}
+
+impl ControlFlowGraph {
+    pub fn build(lines: &[DisassembledLine], roots: &[u32]) -> Self {
+        let mut cfg = ControlFlowGraph::default();
+        let starts: BTreeSet<u32> = lines.iter().filter(|l| is_code(l)).map(|l| l.address).collect();
+
+        let mut leaders: BTreeSet<u32> = roots.iter().copied().filter(|a| starts.contains(a)).collect();
+        let mut previous: Option<&DisassembledLine> = None;
+        for line in lines.iter() {
+            if is_code(line) {
+                if previous.is_none_or(|p| !is_code(p) || ends_block(p)) {
+                    leaders.insert(line.address);
+                }
+                if has_target(line) && starts.contains(&line.address_ref) {
+                    leaders.insert(line.address_ref);
+                }
+            }
+            previous = Some(line);
+        }
+
+        let mut current: Option<BasicBlock> = None;
+        for (index, line) in lines.iter().enumerate() {
+            if !is_code(line) || leaders.contains(&line.address) {
+                if let Some(block) = current.take() {
+                    cfg.blocks.insert(block.start, block);
+                }
+            }
+            if !is_code(line) {
+                continue;
+            }
+
+            let block = current.get_or_insert(BasicBlock { start: line.address, end: line.address, first_line: index, last_line: index });
+            block.end = line.address;
+            block.last_line = index;
+        }
+        if let Some(block) = current.take() {
+            cfg.blocks.insert(block.start, block);
+        }
+
+        for block in cfg.blocks.values() {
+            let last = &lines[block.last_line];
+            let next = last.address + last.size();
+
+            if has_target(last) && starts.contains(&last.address_ref) {
+                let kind = if last.flags & SUBROUTINE_OPCODE != 0 { EdgeKind::Call } else { EdgeKind::Taken };
+                cfg.edges.push(Edge { from: block.start, to: last.address_ref, kind });
+            }
+            if falls_through(last) && cfg.blocks.contains_key(&next) {
+                cfg.edges.push(Edge { from: block.start, to: next, kind: EdgeKind::FallThrough });
+            }
+        }
+
+        let mut function_roots: BTreeSet<u32> = roots.iter().copied().filter(|a| cfg.blocks.contains_key(a)).collect();
+        function_roots.extend(cfg.edges.iter().filter(|e| e.kind == EdgeKind::Call).map(|e| e.to));
+        for root in function_roots {
+            let function = cfg.collect_function(root);
+            cfg.functions.insert(root, function);
+        }
+
+        cfg.add_return_edges(lines);
+        cfg
+    }
+
+    fn collect_function(&self, entry: u32) -> Function {
+        let mut blocks = BTreeSet::new();
+        let mut queue = VecDeque::from([entry]);
+
+        while let Some(start) = queue.pop_front() {
+            if !blocks.insert(start) {
+                continue;
+            }
+            for edge in self.edges.iter().filter(|e| e.from == start) {
+                if edge.kind == EdgeKind::FallThrough || edge.kind == EdgeKind::Taken {
+                    queue.push_back(edge.to);
+                }
+            }
+        }
+
+        Function { entry, blocks }
+    }
+
+    // Ogni blocco che termina con un ritorno torna all'istruzione successiva
+    // a ciascuna chiamata della funzione che lo contiene.
+    fn add_return_edges(&mut self, lines: &[DisassembledLine]) {
+        let mut returns = Vec::new();
+
+        for function in self.functions.values() {
+            let return_sites: Vec<u32> = self.edges.iter()
+                .filter(|e| e.kind == EdgeKind::Call && e.to == function.entry)
+                .filter_map(|e| {
+                    let last = &lines[self.blocks[&e.from].last_line];
+                    let site = last.address + last.size();
+                    self.blocks.contains_key(&site).then_some(site)
+                })
+                .collect();
+
+            for start in &function.blocks {
+                if lines[self.blocks[start].last_line].flags & RETURN_OPCODE == 0 {
+                    continue;
+                }
+                for site in &return_sites {
+                    returns.push(Edge { from: *start, to: *site, kind: EdgeKind::Return });
+                }
+            }
+        }
+
+        self.edges.extend(returns);
+    }
+
+    pub fn blocks(&self) -> impl Iterator<Item = &BasicBlock> {
+        self.blocks.values()
+    }
+
+    pub fn block_at(&self, start: u32) -> Option<&BasicBlock> {
+        self.blocks.get(&start)
+    }
+
+    pub fn block_containing(&self, address: u32) -> Option<&BasicBlock> {
+        self.blocks.range(..=address).next_back().map(|(_, b)| b).filter(|b| b.contains(address))
+    }
+
+    pub fn edges(&self) -> &[Edge] {
+        &self.edges
+    }
+
+    pub fn successors(&self, start: u32) -> impl Iterator<Item = &Edge> {
+        self.edges.iter().filter(move |e| e.from == start)
+    }
+
+    pub fn predecessors(&self, start: u32) -> impl Iterator<Item = &Edge> {
+        self.edges.iter().filter(move |e| e.to == start)
+    }
+
+    pub fn functions(&self) -> impl Iterator<Item = &Function> {
+        self.functions.values()
+    }
+
+    pub fn function(&self, entry: u32) -> Option<&Function> {
+        self.functions.get(&entry)
+    }
+}
+
+impl Dasm {
+    pub(super) fn build_cfg(&mut self) {
+        let memory = self.cpu.get_memory();
+        let mut roots = self.entry_points.clone();
+        roots.push(self.start_pc + memory.get_loaded_address());
+
+        self.cfg = ControlFlowGraph::build(&self.lines, &roots);
+    }
+
+    pub fn get_cfg(&self) -> &ControlFlowGraph {
+        &self.cfg
+    }
+
+    pub(super) fn block_ending_at(&self, line: usize) -> Option<&BasicBlock> {
+        let block = self.cfg.block_containing(self.lines[line].address)?;
+        (block.last_line == line).then_some(block)
+    }
+
+    // Totale da stampare dopo la riga che chiude un blocco: solo per i
+    // blocchi di una funzione o con più di un'istruzione, una singola
+    // istruzione non raggiunta ha già i suoi cicli sulla riga.
+    pub(super) fn block_total_at(&self, line: usize) -> Option<u32> {
+        let block = self.block_ending_at(line)?;
+        let in_function = self.cfg.functions().any(|f| f.blocks.contains(&block.start));
+        (in_function || block.last_line > block.first_line).then(|| self.block_cycles(block))
+    }
+
+    // Cicli del blocco senza penalità: salti non presi, nessuna pagina attraversata.
+    pub fn block_cycles(&self, block: &BasicBlock) -> u32 {
+        self.lines[block.first_line..=block.last_line].iter().map(|l| l.cycles).sum()
+    }
+}
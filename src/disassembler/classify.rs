@@ -0,0 +1,261 @@
+use std::collections::BTreeSet;
+
+use crate::utils::{byte_entropy, find_strings};
+use super::{regions::{DataRegion, RegionType}, types::{DisassembledLine, LineType}, Dasm, BRANCH_OPCODE, INDIRECT_FLAG, JAM_OPCODE, JMP_OPCODE, RETURN_OPCODE, SUBROUTINE_OPCODE, UNDOC_OPCODE};
+
+const PUSH_OPCODES: &[u8] = &[0x48, 0x08];
+const PULL_OPCODES: &[u8] = &[0x68, 0x28];
+const MIN_STRING_CHARS: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GapKind {
+    Code,
+    Data,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum Confidence {
+    Low,
+    Medium,
+    High,
+}
+
+// Indizi raccolti decodificando la zona come se fosse codice.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Evidence {
+    pub instructions: u32,
+    pub undocumented: u32,
+    // Salti e branch verso il mezzo di una riga o verso dati.
+    pub invalid_targets: u32,
+    // Push meno pull: il codice reale tende a restare in pari.
+    pub stack_balance: i32,
+    // Frazione dei byte coperta da stringhe plausibili.
+    pub text_ratio: f64,
+    // Entropia dei byte normalizzata tra 0 e 1.
+    pub entropy: f64,
+    // L'ultima istruzione è RTS, RTI o JMP.
+    pub terminated: bool
+}
+
+impl Evidence {
+    // Positivo per il codice, negativo per i dati.
+    pub fn score(&self) -> f64 {
+        let mut score = 0.0;
+
+        if self.instructions > 0 {
+            let undocumented = self.undocumented as f64 / self.instructions as f64;
+            score -= 2.0 * undocumented;
+            if self.undocumented == 0 {
+                score += 0.5;
+            }
+        }
+        score -= (0.5 * self.invalid_targets as f64).min(1.5);
+        score -= (0.25 * self.stack_balance.unsigned_abs() as f64).min(1.0);
+        score -= 2.0 * self.text_ratio;
+        if self.entropy < 0.5 {
+            score -= 1.0;
+        }
+        if self.terminated {
+            score += 1.0;
+        }
+        score
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Classification {
+    pub start: u32,
+    pub end: u32,
+    pub kind: GapKind,
+    pub confidence: Confidence,
+    pub score: f64,
+    pub evidence: Evidence
+}
+
+impl Classification {
+    fn new(start: u32, end: u32, evidence: Evidence) -> Self {
+        let score = evidence.score();
+        let confidence = match score.abs() {
+            s if s >= 1.5 => Confidence::High,
+            s if s >= 0.75 => Confidence::Medium,
+            _ => Confidence::Low
+        };
+
+        Classification {
+            start,
+            end,
+            kind: if score > 0.0 { GapKind::Code } else { GapKind::Data },
+            confidence,
+            score,
+            evidence
+        }
+    }
+
+    pub fn needs_review(&self) -> bool {
+        self.confidence == Confidence::Low
+    }
+}
+
+// Raggruppa le righe consecutive che formano una zona, chiudendola dopo
+// un RTS, RTI o JMP. Ritorna gli indici della prima e dell'ultima riga.
+fn split_gaps(lines: &[DisassembledLine], in_gap: impl Fn(&DisassembledLine) -> bool) -> Vec<(usize, usize)> {
+    let mut gaps = Vec::new();
+    let mut current: Option<(usize, usize)> = None;
+    for (index, line) in lines.iter().enumerate() {
+        if !in_gap(line) {
+            gaps.extend(current.take());
+            continue;
+        }
+
+        let gap = current.get_or_insert((index, index));
+        gap.1 = index;
+        if line.flags & (RETURN_OPCODE | JMP_OPCODE) != 0 {
+            gaps.extend(current.take());
+        }
+    }
+    gaps.extend(current);
+    gaps
+}
+
+impl Dasm {
+    // Classifica le zone non raggiunte dall'analisi del flusso: righe
+    // decodificate fuori dai blocchi delle funzioni e fuori dalle regioni
+    // dati. Una zona termina dopo un RTS, RTI o JMP.
+    pub fn classify_gaps(&mut self) -> Vec<Classification> {
+        if self.follows_flow() {
+            return self.classify_unreached_bytes();
+        }
+
+        let reached: BTreeSet<u32> = self.cfg.functions().flat_map(|f| f.blocks.iter().copied()).collect();
+        let is_reached = |address: u32| {
+            self.cfg.block_containing(address).is_some_and(|b| reached.contains(&b.start))
+        };
+        let gaps = split_gaps(&self.lines, |line| line.line_type != LineType::Data && !is_reached(line.address));
+
+        gaps.into_iter().map(|(first, last)| self.classification(&self.lines[first..=last])).collect()
+    }
+
+    // Seguendo il flusso le zone non raggiunte sono righe di byte: vengono
+    // decodificate come codice solo per raccogliere gli indizi.
+    fn classify_unreached_bytes(&mut self) -> Vec<Classification> {
+        let mut runs: Vec<(u32, u32)> = Vec::new();
+        for line in &self.lines {
+            let unreached = line.line_type == LineType::Data && self.region_at(line.address).is_none() && !self.reached.contains(&line.address);
+            match runs.last_mut() {
+                Some((_, end)) if unreached && *end == line.address => *end += line.size(),
+                _ if unreached => runs.push((line.address, line.address + line.size())),
+                _ => {}
+            }
+        }
+
+        let mut classifications = Vec::new();
+        for (start, end) in runs {
+            let lines = self.decode_range(start, end);
+            for (first, last) in split_gaps(&lines, |_| true) {
+                classifications.push(self.classification(&lines[first..=last]));
+            }
+        }
+        classifications
+    }
+
+    // Decodifica i byte tra start ed end escluso senza toccare il listato.
+    fn decode_range(&mut self, start: u32, end: u32) -> Vec<DisassembledLine> {
+        let loaded_address = self.cpu.get_memory().get_loaded_address();
+        let mut lines = Vec::new();
+        let mut address = start;
+
+        while address < end {
+            self.cpu.set_pc(address - loaded_address);
+            let Some(line) = self.cpu.disassemble_next() else {
+                break;
+            };
+            if address + line.size() > end {
+                lines.push(self.bytes_line(address, end - address));
+                break;
+            }
+            address += line.size();
+            lines.push(line);
+        }
+        lines
+    }
+
+    fn classification(&self, lines: &[DisassembledLine]) -> Classification {
+        let last = &lines[lines.len() - 1];
+        Classification::new(lines[0].address, last.address + last.size() - 1, self.gap_evidence(lines))
+    }
+
+    fn gap_evidence(&self, lines: &[DisassembledLine]) -> Evidence {
+        let memory = self.cpu.get_memory();
+        let mut evidence = Evidence::default();
+
+        for line in lines {
+            evidence.instructions += 1;
+            if line.flags & (UNDOC_OPCODE | JAM_OPCODE) != 0 {
+                evidence.undocumented += 1;
+            }
+            if line.line_type != LineType::Instruction {
+                continue;
+            }
+
+            let opcode = line.byte_code[0];
+            if PUSH_OPCODES.contains(&opcode) {
+                evidence.stack_balance += 1;
+            }else if PULL_OPCODES.contains(&opcode) {
+                evidence.stack_balance -= 1;
+            }
+
+            let is_branch = line.flags & BRANCH_OPCODE != 0;
+            let has_target = is_branch || line.flags & SUBROUTINE_OPCODE != 0 || (line.flags & JMP_OPCODE != 0 && line.flags & INDIRECT_FLAG == 0);
+            if has_target {
+                let valid = match (lines.iter().find(|l| l.address == line.address_ref), self.line_containing(line.address_ref)) {
+                    (Some(target), _) => target.line_type == LineType::Instruction,
+                    (None, Some(index)) => self.lines[index].address == line.address_ref && self.lines[index].line_type == LineType::Instruction,
+                    (None, None) => !is_branch && memory.to_offset(line.address_ref).is_none()
+                };
+                if !valid {
+                    evidence.invalid_targets += 1;
+                }
+            }
+        }
+
+        let last_line = &lines[lines.len() - 1];
+        evidence.terminated = last_line.line_type == LineType::Instruction && last_line.flags & (RETURN_OPCODE | JMP_OPCODE) != 0;
+
+        let start = lines[0].address;
+        let end = last_line.address + last_line.size();
+        let bytes: Vec<u8> = (start..end).filter_map(|a| memory.to_offset(a)).map(|o| memory.read_byte(o)).collect();
+        let text: u32 = find_strings(&bytes, MIN_STRING_CHARS).iter().map(|s| s.length).sum();
+        evidence.text_ratio = text as f64 / bytes.len() as f64;
+        let max_entropy = (bytes.len().min(256) as f64).log2();
+        evidence.entropy = if max_entropy > 0.0 { byte_entropy(&bytes) / max_entropy } else { 0.0 };
+
+        evidence
+    }
+
+    // Accetta le classificazioni con almeno la confidenza indicata: a ogni
+    // disassemble() i dati diventano regioni di byte, il codice un punto di
+    // ingresso. Serve un nuovo disassemble() per vederne l'effetto.
+    pub fn apply_classification(&mut self, classifications: &[Classification], min_confidence: Confidence) -> usize {
+        let mut applied = 0;
+
+        for classification in classifications.iter().filter(|c| c.confidence >= min_confidence) {
+            if self.classifications.iter().any(|c| c.start == classification.start) {
+                continue;
+            }
+            self.classifications.push(classification.clone());
+            applied += 1;
+        }
+
+        applied
+    }
+
+    pub(super) fn apply_classified_gaps(&mut self) {
+        for index in 0..self.classifications.len() {
+            let (start, end) = (self.classifications[index].start, self.classifications[index].end);
+            match self.classifications[index].kind {
+                GapKind::Data => self.push_region(DataRegion::new(start, end, RegionType::Bytes)),
+                GapKind::Code => { self.push_entry_point(start); }
+            }
+        }
+    }
+}
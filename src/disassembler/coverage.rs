@@ -0,0 +1,41 @@
+use crate::cpus::trace::Trace;
+use super::{regions::{DataRegion, RegionType}, Dasm};
+
+impl Dasm {
+    // Usa la copertura di un'esecuzione: i byte letti o scritti ma mai
+    // eseguiti diventano regioni di byte, le istruzioni eseguite diventano
+    // punti di allineamento per il primo passaggio.
+    pub fn apply_trace(&mut self, trace: &Trace) {
+        self.coverage = Some(trace.clone());
+    }
+
+    pub(super) fn apply_coverage_regions(&mut self) {
+        let Some(trace) = self.coverage.as_ref() else {
+            return;
+        };
+        let memory = self.cpu.get_memory();
+        let data: Vec<u32> = trace.get_reads().union(trace.get_writes())
+            .copied()
+            .filter(|a| memory.to_offset(*a).is_some() && !trace.is_code_byte(*a) && self.region_at(*a).is_none())
+            .collect();
+
+        let mut runs: Vec<(u32, u32)> = Vec::new();
+        for address in data {
+            match runs.last_mut() {
+                Some((_, end)) if *end + 1 == address => *end = address,
+                _ => runs.push((address, address))
+            }
+        }
+        for (start, end) in runs {
+            self.push_region(DataRegion::new(start, end, RegionType::Bytes));
+        }
+    }
+
+    pub fn get_coverage(&self) -> Option<&Trace> {
+        self.coverage.as_ref()
+    }
+
+    pub(super) fn next_executed(&self, address: u32) -> Option<u32> {
+        self.coverage.as_ref()?.next_executed(address)
+    }
+}
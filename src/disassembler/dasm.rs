@@ -1,6 +1,6 @@
-use std::collections::HashMap;
-use crate::{cpus::CpuTrait, disassembler::{BRANCH_OPCODE, JMP_OPCODE, SUBROUTINE_OPCODE}};
-use super::{types::DisassembledLine, Dasm, INDIRECT_FLAG};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use crate::{cpus::CpuTrait, platforms::Platform, disassembler::{BRANCH_OPCODE, DATA_FLAG, JMP_OPCODE, LOAD_FLAG, SELF_MODIFIED_FLAG, STORE_FLAG, SUBROUTINE_OPCODE}};
+use super::{cfg::ControlFlowGraph, types::{DisassembledLine, LineType, OpcodeStability}, Dasm, INDIRECT_FLAG};
 
 impl Dasm {
     pub fn new(cpu: Box<dyn CpuTrait>, start_pc:u32) -> Self {
@@ -8,31 +8,184 @@ impl Dasm {
             cpu,
             start_pc,
             labels_map:HashMap::new(),
+            user_labels:HashMap::new(),
+            user_comments:HashMap::new(),
+            platform: None,
+            platform_symbols:HashMap::new(),
+            io_registers:HashMap::new(),
             lines:Vec::new(),
+            xrefs:BTreeMap::new(),
+            cfg:ControlFlowGraph::default(),
             relative_label_id: 0,
             jump_label_id: 0,
-            subroutine_label_id: 0
+            subroutine_label_id: 0,
+            pointer_label_id: 0,
+            patch_label_id: 0,
+            data_label_id: 0,
+            user_entry_points: Vec::new(),
+            entry_points: Vec::new(),
+            user_regions: Vec::new(),
+            regions: Vec::new(),
+            classifications: Vec::new(),
+            vector_seeding: false,
+            reached: BTreeSet::new(),
+            banking: Vec::new(),
+            coverage: None,
+            zero_page: Vec::new(),
+            interrupt_handlers: Vec::new(),
+            string_min_chars: None,
+            warnings: Vec::new()
         }
     }
 
+    pub fn set_undocumented_opcodes(&mut self, enable:bool) {
+        self.cpu.set_undocumented_opcodes(enable);
+    }
+
+    // Carica i simboli della piattaforma, le label dell'utente hanno
+    // comunque la precedenza.
+    pub fn set_platform(&mut self, platform: Platform) {
+        self.platform = Some(platform);
+        self.platform_symbols = platform.symbols().map(|s| (s.address, s)).collect();
+        self.io_registers = platform.registers().map(|r| (r.address, r)).collect();
+    }
+
+    pub fn get_warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
     pub fn run(&mut self) {
-        self.cpu.set_pc(self.start_pc);
+        self.disassemble();
+        self.print_dasm();
+    }
 
-        self.pass1();
+    pub fn disassemble(&mut self) {
+        self.reset_analysis();
+        if self.vector_seeding {
+            self.seed_vectors();
+        }
+        self.apply_coverage_regions();
+        self.apply_classified_gaps();
+        if let Some(min_chars) = self.string_min_chars {
+            self.detect_strings(min_chars);
+        }
+
+        self.sweep();
+
+        // Le tabelle di dispatch diventano regioni dati e i gestori di
+        // interrupt punti di ingresso: il primo passaggio va ripetuto.
+        while self.detect_dispatch_tables() || self.detect_interrupt_handlers() {
+            self.sweep();
+        }
+
+        self.detect_self_modifying_code();
+        self.symbolize_references();
+        self.pair_immediates();
+        self.analyze_zero_page();
+        self.annotate_io_accesses();
+        self.build_xrefs();
+        self.build_cfg();
+        self.mark_interrupt_exits();
         self.pass2();
-        self.print_dasm();
+    }
+
+    // Regioni e punti di ingresso ripartono da quelli dell'utente.
+    fn reset_analysis(&mut self) {
+        self.regions = self.user_regions.clone();
+        self.entry_points = self.user_entry_points.clone();
+        self.interrupt_handlers.clear();
+    }
+
+    fn sweep(&mut self) {
+        self.reset_lines();
+        self.follow_entry_points();
+        self.cpu.set_pc(self.sweep_start());
+        self.pass1();
+    }
+
+    fn reset_lines(&mut self) {
+        self.lines.clear();
+        self.labels_map = self.user_labels.clone();
+        for handler in &self.interrupt_handlers {
+            self.labels_map.entry(handler.address).or_insert_with(|| handler.name.clone());
+        }
+        self.warnings.clear();
+        self.relative_label_id = 0;
+        self.subroutine_label_id = 0;
+        self.jump_label_id = 0;
+        self.pointer_label_id = 0;
+        self.patch_label_id = 0;
+        self.data_label_id = 0;
+    }
+
+    pub fn get_lines(&self) -> &[DisassembledLine] {
+        &self.lines
+    }
+
+    pub fn get_label(&self, address: u32) -> Option<&String> {
+        self.labels_map.get(&address)
+    }
+
+    // Indice della riga che copre l'indirizzo, le righe sono ordinate.
+    pub(super) fn line_containing(&self, address: u32) -> Option<usize> {
+        let index = self.lines.partition_point(|l| l.address <= address).checked_sub(1)?;
+        let line = &self.lines[index];
+        (address < line.address + line.size()).then_some(index)
     }
 
     fn pass1(&mut self) {
+        let loaded_address = self.cpu.get_memory().get_loaded_address();
+
         loop {
+            let address = self.cpu.get_pc() + loaded_address;
+            if let Some(region) = self.region_at(address) {
+                let next = self.disassemble_region(region, address);
+                self.cpu.set_pc(next - loaded_address);
+                continue;
+            }
+
+            // Seguendo il flusso i byte mai raggiunti restano dati.
+            if self.follows_flow() && !self.reached.contains(&address) {
+                let Some(data) = self.unreached_bytes_line(address) else {
+                    return;
+                };
+                self.cpu.set_pc(address + data.size() - loaded_address);
+                self.lines.push(data);
+                continue;
+            }
+
+            self.label_entry_point(address);
             let res = self.cpu.disassemble_next();
             if res.is_none() {
                 return;
             }
             let mut line=res.unwrap();
 
+            // Un'istruzione che sconfina in una regione dati, o che copre
+            // l'inizio di un'istruzione eseguita, raggiunta o un punto di
+            // ingresso, viene emessa come byte.
+            let boundary = [self.next_region_start(address), self.next_executed(address), self.next_entry_point(address), self.next_reached(address)]
+                .into_iter().flatten().min();
+            if let Some(next) = boundary {
+                if address + line.size() > next {
+                    let data = self.bytes_line(address, next - address);
+                    self.lines.push(data);
+                    self.cpu.set_pc(next - loaded_address);
+                    continue;
+                }
+            }
+
+            if line.line_type == LineType::Instruction && line.is_undocumented() {
+                self.check_undocumented(&mut line);
+            }
+
             if line.flags & BRANCH_OPCODE != 0 || line.flags & SUBROUTINE_OPCODE != 0 || (line.flags & JMP_OPCODE != 0 && line.flags & INDIRECT_FLAG == 0) {
                 self.generate_label(&mut line);
+            }else if !line.address_text.is_empty() {
+                self.symbolize_operand(&mut line);
+            }
+            if line.flags & BRANCH_OPCODE != 0 && line.line_type == LineType::Instruction {
+                self.check_branch_page(&mut line);
             }
 
             self.lines.push(line);
@@ -40,12 +193,37 @@ impl Dasm {
     }
 
     fn pass2(&mut self) {
-
+        for line in self.lines.iter_mut() {
+            if let Some(comment) = self.user_comments.get(&line.address) {
+                if line.comment.is_empty() {
+                    line.comment = comment.clone();
+                }else{
+                    line.comment = format!("{}  {}", comment, line.comment);
+                }
+            }
+        }
     }
 
     pub fn print_dasm(&self) {
+        let equates = self.zero_page_equates();
+        for equate in &equates {
+            println!("{}", equate);
+        }
+        if !equates.is_empty() {
+            println!();
+        }
+
         let mut output_line= String::new();
-        for dasm_line in &self.lines {
+        for (index, dasm_line) in self.lines.iter().enumerate() {
+            if let Some(bank) = self.banking.iter().find(|b| b.start == dasm_line.address) {
+                println!("; bank {} ${:04X}-${:04X}", bank.name, bank.start, bank.end);
+            }
+            if let Some(label) = self.labels_map.get(&dasm_line.address) {
+                println!("{}:", label);
+                for xref in self.xref_comments(dasm_line.address) {
+                    println!("{}", xref);
+                }
+            }
             output_line.push_str(&format!("{:04X}    ",dasm_line.address));
 
             for i in 0..dasm_line.instr_size+1 {
@@ -56,35 +234,105 @@ impl Dasm {
             }else if dasm_line.instr_size == 1 {
                 output_line.push_str("   ");
             }
+            output_line.push_str(&format!("{:<6}", dasm_line.cycles_text()));
             output_line.push_str(&format!("{} ", dasm_line.opcode));
             output_line.push_str(&format!("{} ", dasm_line.operand));
 
-            if dasm_line.comment.len() > 0 {
+            if !dasm_line.comment.is_empty() {
                 output_line.push_str(&format!("   ; {}", dasm_line.comment));
             }
 
             println!("{}", output_line);
             output_line="".to_owned();
+
+            if let Some(total) = self.block_total_at(index) {
+                println!("; {} cycles", total);
+            }
         }
     }
+    fn check_undocumented(&mut self, dasm_line: &mut DisassembledLine) {
+        let warning = match dasm_line.stability() {
+            OpcodeStability::Stable => return,
+            OpcodeStability::Unstable => format!("unstable opcode {}", dasm_line.opcode),
+            OpcodeStability::Jam => String::from("JAM opcode halts the CPU"),
+        };
+
+        self.warnings.push(format!("${:04X}: {}", dasm_line.address, warning));
+        dasm_line.comment = format!("WARNING: {}", warning);
+    }
+
+    // Un branch preso verso un'altra pagina costa un ciclo in più.
+    fn check_branch_page(&mut self, dasm_line: &mut DisassembledLine) {
+        if dasm_line.extra_cycles < 2 {
+            return;
+        }
+        let warning = "branch crosses a page, +1 cycle when taken";
+        self.warnings.push(format!("${:04X}: {}", dasm_line.address, warning));
+        dasm_line.comment = format!("{}  WARNING: {}", dasm_line.comment, warning);
+    }
+
     fn generate_label(&mut self, dasm_line: &mut DisassembledLine) {
-        if self.labels_map.contains_key(&(dasm_line.address as u32)) {
-            dasm_line.operand = self.labels_map.get(&(dasm_line.address as u32)).unwrap().to_string();
+        dasm_line.operand = self.label_for(dasm_line.address_ref, dasm_line.flags);
+        if let Some(symbol) = self.platform_symbols.get(&dasm_line.address_ref) {
+            dasm_line.comment = symbol.description.to_owned();
+            return;
+        }
+        let sign = if dasm_line.address_ref > dasm_line.address { "+" } else { "-" };
+        dasm_line.comment=format!("${:04X} {}", dasm_line.address_ref, sign);
+    }
+
+    // Sostituisce l'indirizzo nell'operando con una label già nota o con
+    // un simbolo della piattaforma.
+    fn symbolize_operand(&mut self, dasm_line: &mut DisassembledLine) {
+        let name = if let Some(label) = self.labels_map.get(&dasm_line.address_ref) {
+            label.clone()
+        }else if let Some(symbol) = self.platform_symbols.get(&dasm_line.address_ref) {
+            self.labels_map.insert(symbol.address, symbol.name.to_owned());
+            symbol.name.to_owned()
         }else{
-            if dasm_line.flags & BRANCH_OPCODE != 0 {
-                dasm_line.operand = format!("LABEL{:0>8}", self.relative_label_id);
-                self.relative_label_id+=1;
-            }else if dasm_line.flags & SUBROUTINE_OPCODE != 0 {
-                dasm_line.operand = format!("SUBRT{:0>8}", self.subroutine_label_id);
-                self.subroutine_label_id+=1;
-            }else{
-                dasm_line.operand = format!("JUMP_{:0>8}", self.jump_label_id);
-                self.jump_label_id+=1;
+            return;
+        };
+
+        dasm_line.operand = dasm_line.operand.replacen(&dasm_line.address_text, &name, 1);
+        if let Some(symbol) = self.platform_symbols.get(&dasm_line.address_ref) {
+            if dasm_line.comment.is_empty() {
+                dasm_line.comment = symbol.description.to_owned();
             }
+        }
+    }
 
-            self.labels_map.insert(dasm_line.address as u32, dasm_line.operand.clone());
-            let sign = if dasm_line.address_ref > dasm_line.address { "+" } else { "-" };
-            dasm_line.comment=format!("${:04X} {}", dasm_line.address_ref, sign);
+    // Ritorna la label associata all'indirizzo, creandone una nuova in base
+    // al tipo di riferimento se non esiste ancora.
+    pub(super) fn label_for(&mut self, address: u32, flags: u32) -> String {
+        if let Some(label) = self.labels_map.get(&address) {
+            return label.clone();
         }
+        if let Some(symbol) = self.platform_symbols.get(&address) {
+            self.labels_map.insert(address, symbol.name.to_owned());
+            return symbol.name.to_owned();
+        }
+
+        let label = if flags & BRANCH_OPCODE != 0 {
+            self.relative_label_id+=1;
+            format!("LABEL{:0>8}", self.relative_label_id-1)
+        }else if flags & SUBROUTINE_OPCODE != 0 {
+            self.subroutine_label_id+=1;
+            format!("SUBRT{:0>8}", self.subroutine_label_id-1)
+        }else if flags & SELF_MODIFIED_FLAG != 0 {
+            self.patch_label_id+=1;
+            format!("PATCH{:0>8}", self.patch_label_id-1)
+        }else if flags & DATA_FLAG != 0 {
+            self.pointer_label_id+=1;
+            format!("PNTR_{:0>8}", self.pointer_label_id-1)
+        }else if flags & (LOAD_FLAG | STORE_FLAG) != 0 {
+            self.data_label_id+=1;
+            format!("DATA_{:0>8}", self.data_label_id-1)
+        }else{
+            self.jump_label_id+=1;
+            format!("JUMP_{:0>8}", self.jump_label_id-1)
+        };
+
+        self.labels_map.insert(address, label.clone());
+        label
     }
 }
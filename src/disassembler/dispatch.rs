@@ -0,0 +1,152 @@
+use super::{regions::{DataRegion, RegionType}, types::{DisassembledLine, LineType}, Dasm};
+
+const LDA_ABSOLUTE_X: u8 = 0xBD;
+const LDA_ABSOLUTE_Y: u8 = 0xB9;
+const STA_ZERO_PAGE: u8 = 0x85;
+const STA_ABSOLUTE: u8 = 0x8D;
+const PHA: u8 = 0x48;
+const JMP_INDIRECT: u8 = 0x6C;
+
+// Quante istruzioni risalire da un JMP (ind) per trovare il caricamento
+// del vettore.
+const JMP_LOOKBACK: usize = 8;
+
+// Tabella di destinazioni letta con un indice. Se hi_table è lo_table+1
+// è una tabella di word, altrimenti due tabelle separate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct DispatchTable {
+    lo_table: u32,
+    hi_table: u32,
+    rts: bool
+}
+
+impl DispatchTable {
+    fn is_words(&self) -> bool {
+        self.hi_table == self.lo_table + 1
+    }
+
+    fn stride(&self) -> u32 {
+        if self.is_words() { 2 } else { 1 }
+    }
+
+    // Le tabelle separate non possono sovrapporsi, l'indice è di 8 bit.
+    fn max_entries(&self) -> u32 {
+        if self.is_words() {
+            128
+        }else{
+            self.lo_table.abs_diff(self.hi_table).min(256)
+        }
+    }
+}
+
+fn is_instruction(line: &DisassembledLine, opcode: u8) -> bool {
+    line.line_type == LineType::Instruction && line.byte_code[0] == opcode
+}
+
+fn indexed_load(line: &DisassembledLine) -> Option<(u8, u32)> {
+    let opcode = line.byte_code[0];
+    (is_instruction(line, LDA_ABSOLUTE_X) || is_instruction(line, LDA_ABSOLUTE_Y)).then_some((opcode, line.address_ref))
+}
+
+// LDA hi,X / PHA / LDA lo,X / PHA: l'RTS successivo salta a destinazione-1.
+fn rts_dispatch(lines: &[DisassembledLine]) -> Option<DispatchTable> {
+    let (hi_index, hi_table) = indexed_load(&lines[0])?;
+    let (lo_index, lo_table) = indexed_load(&lines[2])?;
+    if hi_index != lo_index || !is_instruction(&lines[1], PHA) || !is_instruction(&lines[3], PHA) {
+        return None;
+    }
+    Some(DispatchTable { lo_table, hi_table, rts: true })
+}
+
+// LDA lo,X / STA ptr / LDA hi,X / STA ptr+1 ... JMP (ptr)
+fn indirect_dispatch(lines: &[DisassembledLine]) -> Option<DispatchTable> {
+    let (jump, before) = lines.split_last()?;
+    if !is_instruction(jump, JMP_INDIRECT) {
+        return None;
+    }
+
+    let table_for = |vector: u32| {
+        before.windows(2).rev()
+            .take_while(|w| w[1].line_type == LineType::Instruction)
+            .find(|w| (is_instruction(&w[1], STA_ZERO_PAGE) || is_instruction(&w[1], STA_ABSOLUTE)) && w[1].address_ref == vector)
+            .and_then(|w| indexed_load(&w[0]))
+    };
+
+    let (lo_index, lo_table) = table_for(jump.address_ref)?;
+    let (hi_index, hi_table) = table_for(jump.address_ref + 1)?;
+    if lo_index != hi_index {
+        return None;
+    }
+    Some(DispatchTable { lo_table, hi_table, rts: false })
+}
+
+impl Dasm {
+    // Cerca gli idiomi di dispatch nelle righe del primo passaggio, marca
+    // le tabelle come puntatori e aggiunge le destinazioni ai punti di
+    // ingresso. Ritorna true se ha trovato tabelle nuove.
+    pub(super) fn detect_dispatch_tables(&mut self) -> bool {
+        let mut tables = Vec::new();
+        for (index, window) in self.lines.windows(4).enumerate() {
+            if let Some(table) = rts_dispatch(window) {
+                tables.push((table, index));
+            }
+        }
+        for index in 0..self.lines.len() {
+            let from = (index + 1).saturating_sub(JMP_LOOKBACK + 1);
+            if let Some(table) = indirect_dispatch(&self.lines[from..=index]) {
+                tables.push((table, index));
+            }
+        }
+
+        let mut found = false;
+        for (table, index) in tables {
+            if self.regions.iter().any(|r| r.contains(table.lo_table) || r.contains(table.hi_table)) {
+                continue;
+            }
+            let targets = self.dispatch_targets(&table, self.lines[index].address);
+            if targets.is_empty() {
+                continue;
+            }
+
+            let count = targets.len() as u32;
+            if table.is_words() {
+                let region_type = if table.rts { RegionType::RtsPointers } else { RegionType::Pointers };
+                self.push_region(DataRegion::new(table.lo_table, table.lo_table + count * 2 - 1, region_type));
+            }else{
+                self.push_region(DataRegion::new(table.lo_table, table.lo_table + count - 1, RegionType::LoPointers { hi_table: table.hi_table, rts: table.rts }));
+                self.push_region(DataRegion::new(table.hi_table, table.hi_table + count - 1, RegionType::HiPointers { lo_table: table.lo_table, rts: table.rts }));
+            }
+            for target in targets {
+                self.push_entry_point(target);
+            }
+            found = true;
+        }
+        found
+    }
+
+    // La lunghezza della tabella non è nota: si leggono voci finché puntano
+    // dentro l'immagine e non incontrano codice referenziato o altre regioni.
+    fn dispatch_targets(&self, table: &DispatchTable, dispatch: u32) -> Vec<u32> {
+        let memory = self.cpu.get_memory();
+        let read = |address: u32| memory.to_offset(address).map(|offset| memory.read_byte(offset) as u32);
+        let mut targets = Vec::new();
+
+        for entry in 0..table.max_entries() {
+            let lo_address = table.lo_table + entry * table.stride();
+            let hi_address = table.hi_table + entry * table.stride();
+
+            let claimed = [lo_address, hi_address].iter().any(|a| {
+                (entry > 0 && self.labels_map.contains_key(a)) || self.region_at(*a).is_some() || *a == dispatch
+            });
+            let (Some(lo), Some(hi)) = (read(lo_address), read(hi_address)) else {
+                break;
+            };
+            let target = (hi << 8 | lo) + table.rts as u32;
+            if claimed || memory.to_offset(target).is_none() {
+                break;
+            }
+            targets.push(target);
+        }
+        targets
+    }
+}
@@ -0,0 +1,168 @@
+use crate::cpus::CpuTrait;
+use super::{cfg::{falls_through, has_target}, regions::{DataRegion, RegionType, BYTES_PER_LINE}, types::{DisassembledLine, LineType}, Dasm, JMP_OPCODE};
+
+impl Dasm {
+    // Il disassemblaggio parte dal più basso dei punti di ingresso e segue
+    // il flusso del codice: i byte non raggiunti restano dati.
+    pub fn with_entry_points(cpu: Box<dyn CpuTrait>, entry_points: &[u32]) -> Self {
+        let mut dasm = Dasm::new(cpu, 0);
+        let memory = dasm.cpu.get_memory();
+        dasm.user_entry_points = entry_points.iter().copied().filter(|a| memory.to_offset(*a).is_some()).collect();
+        dasm.user_entry_points.sort();
+        dasm.user_entry_points.dedup();
+        dasm.entry_points = dasm.user_entry_points.clone();
+        dasm.start_from_entry_points();
+        dasm
+    }
+
+    // Aggiunge un punto di ingresso dell'utente dentro l'immagine, ritorna
+    // false se è fuori o se era già presente.
+    pub fn add_entry_point(&mut self, address: u32) -> bool {
+        if self.cpu.get_memory().to_offset(address).is_none() || self.user_entry_points.contains(&address) {
+            return false;
+        }
+        self.user_entry_points.push(address);
+        self.push_entry_point(address);
+        true
+    }
+
+    // Punto di ingresso trovato dall'analisi, non salvato nel progetto.
+    pub(super) fn push_entry_point(&mut self, address: u32) -> bool {
+        if self.cpu.get_memory().to_offset(address).is_none() || self.entry_points.contains(&address) {
+            return false;
+        }
+        self.entry_points.push(address);
+        true
+    }
+
+    // Punti di ingresso dell'utente e dell'analisi.
+    pub fn get_entry_points(&self) -> &[u32] {
+        &self.entry_points
+    }
+
+    pub(super) fn start_from_entry_points(&mut self) {
+        let memory = self.cpu.get_memory();
+        if let Some(offset) = self.user_entry_points.iter().filter_map(|a| memory.to_offset(*a)).min() {
+            self.start_pc = offset;
+        }
+    }
+
+    // Il primo passaggio parte dal punto di ingresso più basso.
+    pub(super) fn sweep_start(&self) -> u32 {
+        let memory = self.cpu.get_memory();
+        self.entry_points.iter().filter_map(|a| memory.to_offset(*a)).fold(self.start_pc, u32::min)
+    }
+
+    // Con l'opzione attiva disassemble() legge i vettori hardware e quelli
+    // della piattaforma.
+    pub fn set_vector_seeding(&mut self, enable: bool) {
+        self.vector_seeding = enable;
+    }
+
+    // Legge dall'immagine i vettori hardware della CPU e, se è impostata
+    // una piattaforma, i vettori di avvio del BASIC e le tabelle di salto
+    // del KERNAL. Ritorna il numero di punti di ingresso aggiunti.
+    pub(super) fn seed_vectors(&mut self) -> usize {
+        let mut vectors: Vec<u32> = self.cpu.hardware_vectors().to_vec();
+        let mut jumps = Vec::new();
+        if let Some(platform) = self.platform {
+            vectors.extend(platform.code_vectors().iter().map(|s| s.address));
+            jumps.extend(platform.jump_tables().iter().flat_map(|t| t.iter()).map(|s| s.address));
+        }
+
+        let mut added = 0;
+        for vector in vectors {
+            let memory = self.cpu.get_memory();
+            let (Some(offset), Some(_)) = (memory.to_offset(vector), memory.to_offset(vector + 1)) else {
+                continue;
+            };
+            let target = memory.read_word_le(offset) as u32;
+            if memory.to_offset(target).is_none() {
+                continue;
+            }
+            if self.region_at(vector).is_none() {
+                self.push_region(DataRegion::new(vector, vector + 1, RegionType::Pointers));
+            }
+            added += self.push_entry_point(target) as usize;
+        }
+        for address in jumps {
+            added += self.push_entry_point(address) as usize;
+        }
+        added
+    }
+
+    // Il flusso si segue solo se l'utente ha indicato dei punti di ingresso
+    // o ha chiesto i vettori, altrimenti il disassemblaggio è lineare.
+    pub(super) fn follows_flow(&self) -> bool {
+        !self.user_entry_points.is_empty() || self.vector_seeding
+    }
+
+    // Raccoglie gli inizi delle istruzioni raggiungibili dai punti di
+    // ingresso, dal punto di partenza e dal codice eseguito in un trace,
+    // seguendo branch, JSR e JMP diretti. Il percorso si ferma su RTS, RTI,
+    // JMP, JAM e BRK, sulle regioni dati e sui byte che non sono istruzioni.
+    pub(super) fn follow_entry_points(&mut self) {
+        self.reached.clear();
+        if !self.follows_flow() {
+            return;
+        }
+
+        let loaded_address = self.cpu.get_memory().get_loaded_address();
+        let mut worklist = self.entry_points.clone();
+        worklist.push(self.start_pc + loaded_address);
+        if let Some(trace) = &self.coverage {
+            worklist.extend(trace.get_executed());
+        }
+
+        while let Some(address) = worklist.pop() {
+            if self.reached.contains(&address) || self.cpu.get_memory().to_offset(address).is_none() || self.region_at(address).is_some() {
+                continue;
+            }
+            self.cpu.set_pc(address - loaded_address);
+            let Some(line) = self.cpu.disassemble_next() else {
+                continue;
+            };
+            let next = address + line.size();
+            let truncated = self.cpu.get_memory().to_offset(next - 1).is_none();
+            let overlaps_region = self.next_region_start(address).is_some_and(|r| next > r);
+            if line.line_type != LineType::Instruction || truncated || overlaps_region {
+                continue;
+            }
+
+            self.reached.insert(address);
+            if has_target(&line) {
+                worklist.push(line.address_ref);
+            }
+            if falls_through(&line) {
+                worklist.push(next);
+            }
+        }
+    }
+
+    pub(super) fn next_reached(&self, address: u32) -> Option<u32> {
+        self.reached.range(address + 1..).next().copied()
+    }
+
+    // Riga di byte per una zona non raggiunta, fino alla prossima
+    // istruzione raggiunta o regione. None alla fine dell'immagine.
+    pub(super) fn unreached_bytes_line(&self, address: u32) -> Option<DisassembledLine> {
+        let memory = self.cpu.get_memory();
+        let end = memory.get_loaded_address() + memory.get_size() as u32;
+        if address >= end {
+            return None;
+        }
+        let limit = [self.next_reached(address), self.next_region_start(address)].into_iter().flatten().fold(end, u32::min);
+        Some(self.bytes_line(address, (limit - address).min(BYTES_PER_LINE)))
+    }
+
+    // Il primo passaggio si riallinea sui punti di ingresso.
+    pub(super) fn next_entry_point(&self, address: u32) -> Option<u32> {
+        self.entry_points.iter().copied().filter(|a| *a > address).min()
+    }
+
+    pub(super) fn label_entry_point(&mut self, address: u32) {
+        if self.entry_points.contains(&address) {
+            self.label_for(address, JMP_OPCODE);
+        }
+    }
+}
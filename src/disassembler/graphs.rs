@@ -0,0 +1,127 @@
+use std::fmt::Write;
+
+use super::{cfg::EdgeKind, Dasm, INDIRECT_FLAG, JMP_OPCODE, SUBROUTINE_OPCODE};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GraphFormat {
+    Dot,
+    Mermaid,
+}
+
+// Grafo indipendente dal formato, prima della serializzazione.
+struct Graph {
+    name: String,
+    nodes: Vec<(u32, String)>,
+    edges: Vec<(u32, u32, String)>
+}
+
+impl Graph {
+    fn new(name: &str) -> Self {
+        Graph {
+            name: name.to_owned(),
+            nodes: Vec::new(),
+            edges: Vec::new()
+        }
+    }
+
+    fn add_node(&mut self, address: u32, label: String) {
+        if !self.nodes.iter().any(|(a, _)| *a == address) {
+            self.nodes.push((address, label));
+        }
+    }
+
+    fn add_edge(&mut self, from: u32, to: u32, label: &str) {
+        let edge = (from, to, label.to_owned());
+        if !self.edges.contains(&edge) {
+            self.edges.push(edge);
+        }
+    }
+
+    fn render(&self, format: GraphFormat) -> String {
+        let mut output = String::new();
+
+        match format {
+            GraphFormat::Dot => {
+                writeln!(output, "digraph \"{}\" {{", self.name).unwrap();
+                writeln!(output, "    node [shape=box, fontname=\"monospace\"];").unwrap();
+                for (address, label) in &self.nodes {
+                    writeln!(output, "    n_{:04X} [label=\"{}\"];", address, label.replace('"', "\\\"").replace('\n', "\\n")).unwrap();
+                }
+                for (from, to, label) in &self.edges {
+                    let style = if label == "call" || label == "return" { ", style=dashed" } else { "" };
+                    writeln!(output, "    n_{:04X} -> n_{:04X} [label=\"{}\"{}];", from, to, label, style).unwrap();
+                }
+                writeln!(output, "}}").unwrap();
+            },
+            GraphFormat::Mermaid => {
+                writeln!(output, "flowchart TD").unwrap();
+                writeln!(output, "    %% {}", self.name).unwrap();
+                for (address, label) in &self.nodes {
+                    writeln!(output, "    n_{:04X}[\"{}\"]", address, label.replace('"', "#quot;").replace('\n', "<br/>")).unwrap();
+                }
+                for (from, to, label) in &self.edges {
+                    let arrow = if label == "call" || label == "return" { "-.->" } else { "-->" };
+                    writeln!(output, "    n_{:04X} {}|{}| n_{:04X}", from, arrow, label, to).unwrap();
+                }
+            }
+        }
+
+        output
+    }
+}
+
+impl Dasm {
+    fn node_name(&self, address: u32) -> String {
+        self.labels_map.get(&address).cloned().unwrap_or_else(|| format!("${:04X}", address))
+    }
+
+    // Grafo delle chiamate tra funzioni, compresi i salti in coda verso
+    // altre funzioni e le routine esterne all'immagine.
+    pub fn export_call_graph(&self, format: GraphFormat) -> String {
+        let mut graph = Graph::new("call graph");
+
+        for function in self.cfg.functions() {
+            graph.add_node(function.entry, self.node_name(function.entry));
+
+            for block in function.blocks.iter().filter_map(|b| self.cfg.block_at(*b)) {
+                let last = &self.lines[block.last_line];
+                let kind = if last.flags & SUBROUTINE_OPCODE != 0 {
+                    "call"
+                }else if last.flags & JMP_OPCODE != 0 && last.flags & INDIRECT_FLAG == 0 && !function.blocks.contains(&last.address_ref) {
+                    "jump"
+                }else{
+                    continue;
+                };
+
+                graph.add_node(last.address_ref, self.node_name(last.address_ref));
+                graph.add_edge(function.entry, last.address_ref, kind);
+            }
+        }
+
+        graph.render(format)
+    }
+
+    // Grafo dei blocchi di una funzione, None se l'indirizzo non è la
+    // radice di una funzione.
+    pub fn export_function_cfg(&self, entry: u32, format: GraphFormat) -> Option<String> {
+        let function = self.cfg.function(entry)?;
+        let mut graph = Graph::new(&self.node_name(entry));
+
+        for block in function.blocks.iter().filter_map(|b| self.cfg.block_at(*b)) {
+            graph.add_node(block.start, format!("{}\n${:04X}-${:04X}", self.node_name(block.start), block.start, block.end));
+        }
+
+        for start in &function.blocks {
+            for edge in self.cfg.successors(*start) {
+                match edge.kind {
+                    EdgeKind::Return => continue,
+                    EdgeKind::Call => graph.add_node(edge.to, self.node_name(edge.to)),
+                    _ => {}
+                }
+                graph.add_edge(edge.from, edge.to, &edge.kind.to_string());
+            }
+        }
+
+        Some(graph.render(format))
+    }
+}
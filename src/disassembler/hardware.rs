@@ -0,0 +1,74 @@
+use crate::{cpus::mos6510::registers::{REG_A, REG_X, REG_Y}, platforms::IoRegister};
+use super::{operands::{immediate_register, STORES}, types::{LineType, MemoryAccess}, Dasm, BRANCH_OPCODE, JMP_OPCODE, RETURN_OPCODE, SUBROUTINE_OPCODE};
+
+impl Dasm {
+    // Aggiunge le descrizioni di altri registri di I/O, sostituendo
+    // quelle della piattaforma allo stesso indirizzo.
+    pub fn add_io_registers(&mut self, registers: &'static [IoRegister]) {
+        self.io_registers.extend(registers.iter().map(|r| (r.address, r)));
+    }
+
+    // Commenta ogni accesso a un registro di I/O noto. Se il valore scritto
+    // viene da un immediato caricato nella stessa sequenza lineare ne
+    // decodifica i bit, come "25 rows, screen on, YSCROLL=3" per $D011.
+    pub(super) fn annotate_io_accesses(&mut self) {
+        let mut registers: [(u32, Option<u8>); 3] = [(REG_A, None), (REG_X, None), (REG_Y, None)];
+
+        for index in 0..self.lines.len() {
+            let line = &self.lines[index];
+            if line.line_type != LineType::Instruction || self.labels_map.contains_key(&line.address) {
+                registers.iter_mut().for_each(|r| r.1 = None);
+                if line.line_type != LineType::Instruction {
+                    continue;
+                }
+            }
+
+            // Con un indice o un'indirezione il registro toccato non è noto.
+            let opcode = line.byte_code[0];
+            let register = self.io_registers.get(&line.address_ref).filter(|_| line.has_reference() && !line.operand.contains(','));
+            let annotation = register.and_then(|register| {
+                let stored = STORES.iter().find(|(o, _)| *o == opcode)
+                    .and_then(|(_, source)| registers.iter().find(|r| r.0 == *source))
+                    .and_then(|r| r.1);
+                match (line.memory_access(), stored) {
+                    (MemoryAccess::None, _) => None,
+                    (MemoryAccess::Read, _) => Some(register.read.to_string()),
+                    (_, None) => Some(register.write.to_string()),
+                    (_, Some(value)) => {
+                        let decoded = register.decode(value);
+                        if decoded.is_empty() {
+                            Some(format!("{} = ${:02X}", register.write, value))
+                        }else{
+                            Some(format!("{}: {}", register.write, decoded))
+                        }
+                    }
+                }
+            });
+
+            for register in registers.iter_mut() {
+                if line.regs_written & register.0 != 0 {
+                    register.1 = None;
+                }
+            }
+            let loaded = immediate_register(opcode);
+            if let Some(register) = registers.iter_mut().find(|r| Some(r.0) == loaded) {
+                register.1 = Some(line.byte_code[1]);
+            }
+            if line.flags & (BRANCH_OPCODE | SUBROUTINE_OPCODE | JMP_OPCODE | RETURN_OPCODE) != 0 {
+                registers.iter_mut().for_each(|r| r.1 = None);
+            }
+
+            let Some(annotation) = annotation else {
+                continue;
+            };
+            // La descrizione del registro prende il posto di quella del simbolo.
+            let description = self.platform_symbols.get(&line.address_ref).map(|s| s.description).unwrap_or_default();
+            let line = &mut self.lines[index];
+            if line.comment.is_empty() || line.comment == description {
+                line.comment = annotation;
+            }else{
+                line.comment = format!("{}  {}", line.comment, annotation);
+            }
+        }
+    }
+}
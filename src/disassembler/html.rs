@@ -0,0 +1,114 @@
+use std::{collections::HashMap, fmt::Write, fs, io, path::Path};
+
+use super::{regions::RegionType, types::{DisassembledLine, LineType}, Dasm};
+
+const STYLE: &str = "\
+body { background: #1e1e1e; color: #d4d4d4; font-family: monospace; }
+.line { white-space: pre; }
+.line:target, .label:target { background: #264f78; }
+.addr { color: #858585; }
+.bytes { color: #6a6a6a; }
+.code .mnemonic { color: #569cd6; }
+.data .mnemonic { color: #c586c0; }
+.text .mnemonic, .text .operand { color: #ce9178; }
+.label { color: #dcdcaa; margin-top: 0.5em; }
+.xrefs, .comment { color: #6a9955; }
+a { color: inherit; }
+summary { cursor: pointer; color: #dcdcaa; }
+";
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+// Operando con ogni label definita nel listato trasformata in un link,
+// anche dentro espressioni come #<label o label+2.
+fn link_labels(operand: &str, defined: &HashMap<&str, u32>) -> String {
+    let mut output = String::new();
+    let mut rest = operand;
+    while let Some(start) = rest.find(|c: char| c.is_ascii_alphabetic() || c == '_') {
+        let end = rest[start..].find(|c: char| !c.is_ascii_alphanumeric() && c != '_').map_or(rest.len(), |e| start + e);
+        let word = &rest[start..end];
+        output.push_str(&escape(&rest[..start]));
+        match defined.get(word) {
+            Some(address) => write!(output, "<a href=\"#L_{:04X}\">{}</a>", address, word).unwrap(),
+            None => output.push_str(&escape(word))
+        }
+        rest = &rest[end..];
+    }
+    output.push_str(&escape(rest));
+    output
+}
+
+impl Dasm {
+    // Classe CSS della riga: codice, dati o testo.
+    fn html_class(&self, line: &DisassembledLine) -> &'static str {
+        match line.line_type {
+            LineType::Instruction => "code",
+            _ => match self.region_at(line.address).map(|r| r.region_type) {
+                Some(RegionType::Petscii | RegionType::ScreenCodes | RegionType::Shifted | RegionType::NullTerminated) => "text",
+                _ => "data"
+            }
+        }
+    }
+
+    // Listato HTML autonomo: ogni label rimanda alla definizione, ogni
+    // definizione elenca chi la usa e ogni subroutine si può chiudere.
+    pub fn export_html(&self, title: &str) -> String {
+        let mut output = String::new();
+        writeln!(output, "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>\n{}</style>\n</head>\n<body>", escape(title), STYLE).unwrap();
+
+        for equate in self.zero_page_equates() {
+            writeln!(output, "<div class=\"line equate\">{}</div>", escape(&equate)).unwrap();
+        }
+
+        let defined: HashMap<&str, u32> = self.lines.iter()
+            .filter_map(|l| self.labels_map.get(&l.address).map(|name| (name.as_str(), l.address)))
+            .collect();
+
+        let mut open = false;
+        for line in &self.lines {
+            let entry = line.line_type == LineType::Instruction && self.cfg.function(line.address).is_some();
+            if open && (entry || line.line_type != LineType::Instruction) {
+                output.push_str("</details>\n");
+                open = false;
+            }
+            if entry {
+                let name = self.labels_map.get(&line.address).cloned().unwrap_or_else(|| format!("${:04X}", line.address));
+                writeln!(output, "<details open>\n<summary>{}</summary>", escape(&name)).unwrap();
+                open = true;
+            }
+
+            if let Some(label) = self.labels_map.get(&line.address) {
+                writeln!(output, "<div class=\"label\" id=\"L_{:04X}\">{}:</div>", line.address, escape(label)).unwrap();
+                let callers: Vec<String> = self.get_xrefs(line.address).iter()
+                    .map(|x| format!("<a href=\"#A_{:04X}\">${:04X}</a> {}", x.from, x.from, x.kind))
+                    .collect();
+                if !callers.is_empty() {
+                    writeln!(output, "<div class=\"xrefs\">; xref: {}</div>", callers.join(", ")).unwrap();
+                }
+            }
+
+            // Le parole dentro una stringa non sono label.
+            let class = self.html_class(line);
+            let operand = if class == "text" { escape(&line.operand) } else { link_labels(&line.operand, &defined) };
+            let bytes: Vec<String> = line.byte_code[..line.instr_size as usize + 1].iter().map(|b| format!("{:02X}", b)).collect();
+            write!(output, "<div class=\"line {}\" id=\"A_{:04X}\"><span class=\"addr\">{:04X}</span>  <span class=\"bytes\">{:<9}</span> <span class=\"mnemonic\">{}</span> <span class=\"operand\">{}</span>",
+                class, line.address, line.address, bytes.join(" "), escape(&line.opcode), operand).unwrap();
+            if !line.comment.is_empty() {
+                write!(output, "   <span class=\"comment\">; {}</span>", escape(&line.comment)).unwrap();
+            }
+            output.push_str("</div>\n");
+        }
+        if open {
+            output.push_str("</details>\n");
+        }
+
+        output.push_str("</body>\n</html>\n");
+        output
+    }
+
+    pub fn save_html<P: AsRef<Path>>(&self, path: P, title: &str) -> io::Result<()> {
+        fs::write(path, self.export_html(title))
+    }
+}
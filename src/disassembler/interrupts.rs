@@ -0,0 +1,72 @@
+use super::{Dasm, JMP_OPCODE, RETURN_OPCODE};
+
+// Vettori in RAM del KERNAL per IRQ e NMI e vettori hardware.
+const INTERRUPT_VECTORS: &[u32] = &[0x0314, 0x0318, 0xFFFA, 0xFFFE];
+const RTI: u8 = 0x40;
+
+// Gestore installato scrivendo il suo indirizzo in un vettore.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InterruptHandler {
+    pub vector: u32,
+    pub address: u32,
+    pub name: String,
+    // Istruzioni con cui il gestore termina: RTI o JMP al KERNAL.
+    pub exits: Vec<u32>
+}
+
+impl Dasm {
+    // Cerca le installazioni di gestori, come LDA #<irq / STA $0314 /
+    // LDA #>irq / STA $0315, e ne fa punti di ingresso con label
+    // irq_handler_N. Ritorna true se ne ha trovati di nuovi.
+    pub(super) fn detect_interrupt_handlers(&mut self) -> bool {
+        let mut found = false;
+
+        for (vector, (_, lo), (_, hi)) in self.immediate_pairs() {
+            if !INTERRUPT_VECTORS.contains(&vector) {
+                continue;
+            }
+            let address = (hi as u32) << 8 | lo as u32;
+            if self.cpu.get_memory().to_offset(address).is_none() || self.interrupt_handlers.iter().any(|h| h.address == address) {
+                continue;
+            }
+
+            let name = format!("irq_handler_{}", self.interrupt_handlers.len());
+            self.interrupt_handlers.push(InterruptHandler { vector, address, name, exits: Vec::new() });
+            self.push_entry_point(address);
+            found = true;
+        }
+        found
+    }
+
+    pub fn get_interrupt_handlers(&self) -> &[InterruptHandler] {
+        &self.interrupt_handlers
+    }
+
+    // Le uscite di un gestore sono gli RTI e i salti alle routine del
+    // KERNAL raggiungibili dal suo ingresso.
+    pub(super) fn mark_interrupt_exits(&mut self) {
+        let kernal_exits = self.platform.map(|p| p.interrupt_exits()).unwrap_or_default();
+
+        for index in 0..self.interrupt_handlers.len() {
+            let Some(function) = self.cfg.function(self.interrupt_handlers[index].address) else {
+                continue;
+            };
+            let exits: Vec<usize> = function.blocks.iter()
+                .map(|start| self.cfg.block_at(*start).unwrap().last_line)
+                .filter(|line| {
+                    let line = &self.lines[*line];
+                    (line.flags & RETURN_OPCODE != 0 && line.byte_code[0] == RTI)
+                        || (line.flags & JMP_OPCODE != 0 && kernal_exits.contains(&line.address_ref))
+                })
+                .collect();
+
+            let name = self.interrupt_handlers[index].name.clone();
+            self.interrupt_handlers[index].exits = exits.iter().map(|l| self.lines[*l].address).collect();
+            for line in exits {
+                let line = &mut self.lines[line];
+                let note = format!("end of {}", name);
+                line.comment = if line.comment.is_empty() { note } else { format!("{}  {}", line.comment, note) };
+            }
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use serde::Serialize;
+
+use super::{project::{hex_address, LabelEntry}, regions::DataRegion, types::{DisassembledLine, LineType}, Dasm,
+    BRANCH_OPCODE, DATA_FLAG, INDIRECT_FLAG, INVALID_OPCODE, JAM_OPCODE, JMP_OPCODE, LOAD_FLAG, RETURN_OPCODE,
+    SELF_MODIFIED_FLAG, STORE_FLAG, SUBROUTINE_OPCODE, UNDOC_OPCODE, UNSTABLE_OPCODE, VALID_OPCODE};
+
+// Va incrementata a ogni modifica incompatibile dei campi esportati.
+pub const JSON_FORMAT_VERSION: u32 = 1;
+
+// I flag vengono esportati per nome, così i valori dei bit restano interni.
+const FLAG_NAMES: &[(u32, &str)] = &[
+    (VALID_OPCODE, "valid"),
+    (UNDOC_OPCODE, "undocumented"),
+    (BRANCH_OPCODE, "branch"),
+    (SUBROUTINE_OPCODE, "subroutine"),
+    (JMP_OPCODE, "jump"),
+    (INVALID_OPCODE, "invalid"),
+    (DATA_FLAG, "data"),
+    (LOAD_FLAG, "load"),
+    (STORE_FLAG, "store"),
+    (INDIRECT_FLAG, "indirect"),
+    (UNSTABLE_OPCODE, "unstable"),
+    (JAM_OPCODE, "jam"),
+    (RETURN_OPCODE, "return"),
+    (SELF_MODIFIED_FLAG, "self_modified"),
+];
+
+fn is_one(value: &u32) -> bool {
+    *value == 1
+}
+
+#[derive(Debug, Serialize)]
+struct JsonLine<'a> {
+    #[serde(with = "hex_address")]
+    address: u32,
+    #[serde(rename = "type")]
+    line_type: &'a LineType,
+    bytes: &'a [u8],
+    #[serde(skip_serializing_if = "is_one")]
+    repeat: u32,
+    mnemonic: &'a str,
+    operand: &'a str,
+    flags: Vec<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    address_ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<&'a str>,
+    comment: &'a str
+}
+
+#[derive(Debug, Serialize)]
+struct JsonListing<'a> {
+    version: u32,
+    #[serde(with = "hex_address")]
+    load_address: u32,
+    #[serde(with = "hex_address::list")]
+    entry_points: Vec<u32>,
+    labels: Vec<LabelEntry>,
+    regions: &'a [DataRegion],
+    lines: Vec<JsonLine<'a>>
+}
+
+// Un record per riga nel formato JSON Lines, distinto dal campo "record".
+#[derive(Debug, Serialize)]
+#[serde(tag = "record", rename_all = "lowercase")]
+enum JsonRecord<'a> {
+    Header {
+        version: u32,
+        #[serde(with = "hex_address")]
+        load_address: u32,
+        #[serde(with = "hex_address::list")]
+        entry_points: Vec<u32>
+    },
+    Label(LabelEntry),
+    Region(&'a DataRegion),
+    Line(JsonLine<'a>),
+}
+
+impl Dasm {
+    fn json_line<'a>(&'a self, line: &'a DisassembledLine) -> JsonLine<'a> {
+        JsonLine {
+            address: line.address,
+            line_type: &line.line_type,
+            bytes: &line.byte_code[..line.instr_size as usize + 1],
+            repeat: line.repeat_count,
+            mnemonic: &line.opcode,
+            operand: &line.operand,
+            flags: FLAG_NAMES.iter().filter(|(flag, _)| line.flags & flag != 0).map(|(_, name)| *name).collect(),
+            address_ref: line.has_reference().then(|| format!("${:04X}", line.address_ref)),
+            label: self.labels_map.get(&line.address).map(String::as_str),
+            comment: &line.comment
+        }
+    }
+
+    fn json_labels(&self) -> Vec<LabelEntry> {
+        self.get_symbols().into_iter().map(|s| LabelEntry { address: s.address, name: s.name }).collect()
+    }
+
+    fn json_entry_points(&self) -> Vec<u32> {
+        let start = self.start_pc + self.cpu.get_memory().get_loaded_address();
+        std::iter::once(start).chain(self.entry_points.iter().copied().filter(|a| *a != start)).collect()
+    }
+
+    // Listato completo in un solo documento JSON: righe, label e regioni.
+    pub fn export_json(&self) -> String {
+        let listing = JsonListing {
+            version: JSON_FORMAT_VERSION,
+            load_address: self.cpu.get_memory().get_loaded_address(),
+            entry_points: self.json_entry_points(),
+            labels: self.json_labels(),
+            regions: &self.regions,
+            lines: self.lines.iter().map(|l| self.json_line(l)).collect()
+        };
+        let mut output = serde_json::to_string_pretty(&listing).unwrap();
+        output.push('\n');
+        output
+    }
+
+    // Stesso contenuto di export_json, un oggetto per riga: prima
+    // l'intestazione con la versione, poi label, regioni e righe.
+    pub fn export_jsonl(&self) -> String {
+        let header = JsonRecord::Header {
+            version: JSON_FORMAT_VERSION,
+            load_address: self.cpu.get_memory().get_loaded_address(),
+            entry_points: self.json_entry_points()
+        };
+        let records = std::iter::once(header)
+            .chain(self.json_labels().into_iter().map(JsonRecord::Label))
+            .chain(self.regions.iter().map(JsonRecord::Region))
+            .chain(self.lines.iter().map(|l| JsonRecord::Line(self.json_line(l))));
+
+        let mut output = String::new();
+        for record in records {
+            output.push_str(&serde_json::to_string(&record).unwrap());
+            output.push('\n');
+        }
+        output
+    }
+}
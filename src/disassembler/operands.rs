@@ -0,0 +1,165 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::cpus::mos6510::registers::{REG_A, REG_X, REG_Y};
+use super::{types::LineType, Dasm, BRANCH_OPCODE, DATA_FLAG, JMP_OPCODE, RETURN_OPCODE, STORE_FLAG, SUBROUTINE_OPCODE};
+
+const ZERO_PAGE_END: u32 = 0x100;
+
+impl Dasm {
+    // Esprime con una label ogni operando che punta dentro l'immagine.
+    // Un indirizzo che cade all'interno di un'istruzione o di un dato
+    // diventa label+n sulla riga che lo contiene, così il listato resta
+    // rilocabile.
+    pub(super) fn symbolize_references(&mut self) {
+        let mut orphans = BTreeSet::new();
+
+        for index in 0..self.lines.len() {
+            let line = &self.lines[index];
+            if !line.has_reference() {
+                continue;
+            }
+            let target = line.address_ref;
+            let Some(container) = self.line_containing(target) else {
+                continue;
+            };
+            let base = self.lines[container].address;
+            let flags = line.flags;
+
+            // Operando ancora numerico, oppure label generata nel primo
+            // passaggio su un indirizzo che non è l'inizio di una riga.
+            let replaced = if line.operand.contains(&line.address_text) {
+                line.address_text.clone()
+            }else if target != base && self.is_generated_label(target) {
+                // La label generata passa all'inizio della riga se è libero.
+                let name = self.labels_map[&target].clone();
+                self.labels_map.entry(base).or_insert_with(|| name.clone());
+                orphans.insert(target);
+                name
+            }else{
+                continue;
+            };
+
+            let label = self.label_for(base, flags);
+            let expression = if target == base { label } else { format!("{}+{}", label, target - base) };
+            let line = &mut self.lines[index];
+            line.operand = line.operand.replacen(&replaced, &expression, 1);
+        }
+
+        for address in orphans {
+            self.labels_map.remove(&address);
+        }
+    }
+
+    fn is_generated_label(&self, address: u32) -> bool {
+        self.labels_map.contains_key(&address) && !self.user_labels.contains_key(&address) && !self.platform_symbols.contains_key(&address)
+    }
+}
+
+const LDA_IMMEDIATE: u8 = 0xA9;
+const LDX_IMMEDIATE: u8 = 0xA2;
+const LDY_IMMEDIATE: u8 = 0xA0;
+// Store assolute e in pagina zero, senza indice, con il registro scritto.
+pub(super) const STORES: &[(u8, u32)] = &[
+    (0x85, REG_A), (0x8D, REG_A),
+    (0x86, REG_X), (0x8E, REG_X),
+    (0x84, REG_Y), (0x8C, REG_Y),
+];
+
+// Registro caricato con un immediato: riga e valore.
+pub(super) type Immediate = (usize, u8);
+
+// Registro caricato da un LDA, LDX o LDY immediato.
+pub(super) fn immediate_register(opcode: u8) -> Option<u32> {
+    match opcode {
+        LDA_IMMEDIATE => Some(REG_A),
+        LDX_IMMEDIATE => Some(REG_X),
+        LDY_IMMEDIATE => Some(REG_Y),
+        _ => None
+    }
+}
+
+impl Dasm {
+    // Coppie di immediati scritte in due locazioni adiacenti, come in
+    // LDA #$00 / STA $FB / LDA #$C0 / STA $FC: indirizzo del byte basso,
+    // immediato basso e alto. L'analisi resta dentro una sequenza lineare
+    // di istruzioni.
+    pub(super) fn immediate_pairs(&self) -> Vec<(u32, Immediate, Immediate)> {
+        let mut registers: [(u32, Option<Immediate>); 3] = [(REG_A, None), (REG_X, None), (REG_Y, None)];
+        let mut stored: BTreeMap<u32, Immediate> = BTreeMap::new();
+        let mut pairs = Vec::new();
+
+        for (index, line) in self.lines.iter().enumerate() {
+            if line.line_type != LineType::Instruction || self.labels_map.contains_key(&line.address) {
+                registers.iter_mut().for_each(|r| r.1 = None);
+                stored.clear();
+                if line.line_type != LineType::Instruction {
+                    continue;
+                }
+            }
+
+            let opcode = line.byte_code[0];
+            if let Some((_, register)) = STORES.iter().find(|(o, _)| *o == opcode) {
+                let address = line.address_ref;
+                match registers.iter().find(|r| r.0 == *register).and_then(|r| r.1) {
+                    Some(immediate) => { stored.insert(address, immediate); },
+                    None => { stored.remove(&address); }
+                }
+                let lo_address = address.wrapping_sub(1);
+                if let (Some(lo), Some(hi)) = (stored.get(&lo_address), stored.get(&address)) {
+                    pairs.push((lo_address, *lo, *hi));
+                }
+                if let (Some(lo), Some(hi)) = (stored.get(&address), stored.get(&(address + 1))) {
+                    pairs.push((address, *lo, *hi));
+                }
+            }else if line.flags & STORE_FLAG != 0 && line.has_reference() {
+                stored.remove(&line.address_ref);
+            }
+
+            for register in registers.iter_mut() {
+                if line.regs_written & register.0 != 0 {
+                    register.1 = None;
+                }
+            }
+            let loaded = immediate_register(opcode);
+            if let Some(register) = registers.iter_mut().find(|r| Some(r.0) == loaded) {
+                register.1 = Some((index, line.byte_code[1]));
+            }
+
+            if line.flags & (BRANCH_OPCODE | SUBROUTINE_OPCODE | JMP_OPCODE | RETURN_OPCODE) != 0 {
+                registers.iter_mut().for_each(|r| r.1 = None);
+                stored.clear();
+            }
+        }
+        pairs
+    }
+
+    // Riscrive le coppie di immediati come #<label e #>label. Solo i
+    // puntatori in pagina zero e i vettori di interrupt: due scritture su
+    // registri assoluti consecutivi, come $D020/$D021, sono quasi sempre
+    // valori distinti.
+    pub(super) fn pair_immediates(&mut self) {
+        for (lo_address, (lo_line, lo), (hi_line, hi)) in self.immediate_pairs() {
+            let is_pointer = lo_address < ZERO_PAGE_END || self.interrupt_handlers.iter().any(|h| h.vector == lo_address);
+            if !is_pointer || lo_line == hi_line {
+                continue;
+            }
+            let Some(name) = self.pointer_expression((hi as u32) << 8 | lo as u32) else {
+                continue;
+            };
+            let name = if name.contains('+') { format!("({})", name) } else { name };
+            self.lines[lo_line].operand = format!("#<{}", name);
+            self.lines[hi_line].operand = format!("#>{}", name);
+        }
+    }
+
+    // Label per un indirizzo dentro l'immagine, anche label+n, oppure
+    // il simbolo della piattaforma.
+    fn pointer_expression(&mut self, address: u32) -> Option<String> {
+        if let Some(container) = self.line_containing(address) {
+            let base = self.lines[container].address;
+            let label = self.label_for(base, DATA_FLAG);
+            return Some(if base == address { label } else { format!("{}+{}", label, address - base) });
+        }
+        self.platform_symbols.get(&address).map(|s| s.name.to_owned())
+    }
+}
@@ -0,0 +1,228 @@
+use std::{fs, io, path::{Path, PathBuf}};
+use serde::{Deserialize, Serialize};
+
+use crate::{memory::BinaryBuffer, platforms::Platform, utils::crc32};
+use super::{regions::DataRegion, Dasm};
+
+pub const PROJECT_VERSION: u32 = 1;
+
+// File di progetto: tutto ciò che l'utente ha scoperto su un binario e che
+// deve sopravvivere tra un'esecuzione e l'altra. Gli indirizzi sono salvati
+// come stringhe "$XXXX" per restare leggibili sotto git.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Project {
+    pub version: u32,
+    pub binary: String,
+    #[serde(with = "hex_crc")]
+    pub crc32: u32,
+    #[serde(with = "hex_address")]
+    pub load_address: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub platform: Option<Platform>,
+    #[serde(default, with = "hex_address::list")]
+    pub entry_points: Vec<u32>,
+    #[serde(default)]
+    pub labels: Vec<LabelEntry>,
+    #[serde(default)]
+    pub comments: Vec<CommentEntry>,
+    #[serde(default)]
+    pub regions: Vec<DataRegion>,
+    #[serde(default)]
+    pub banking: Vec<BankEntry>,
+    #[serde(skip)]
+    base_dir: PathBuf
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LabelEntry {
+    #[serde(with = "hex_address")]
+    pub address: u32,
+    pub name: String
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommentEntry {
+    #[serde(with = "hex_address")]
+    pub address: u32,
+    pub text: String
+}
+
+// Configurazione di banco attiva su un intervallo, ad es. "BASIC ROM".
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BankEntry {
+    #[serde(with = "hex_address")]
+    pub start: u32,
+    #[serde(with = "hex_address")]
+    pub end: u32,
+    pub name: String
+}
+
+impl Project {
+    pub fn new(binary: &str, memory: &BinaryBuffer) -> Self {
+        Project {
+            version: PROJECT_VERSION,
+            binary: binary.to_owned(),
+            crc32: crc32(memory.get_data()),
+            load_address: memory.get_loaded_address(),
+            platform: None,
+            entry_points: Vec::new(),
+            labels: Vec::new(),
+            comments: Vec::new(),
+            regions: Vec::new(),
+            banking: Vec::new(),
+            base_dir: PathBuf::new()
+        }
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let text = fs::read_to_string(&path)?;
+        let mut project: Project = serde_json::from_str(&text)?;
+
+        if project.version > PROJECT_VERSION {
+            return Err(io::Error::new(io::ErrorKind::InvalidData,
+                format!("Project version {} is newer than supported version {}", project.version, PROJECT_VERSION)));
+        }
+        project.base_dir = path.as_ref().parent().map(Path::to_path_buf).unwrap_or_default();
+        Ok(project)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut text = serde_json::to_string_pretty(self)?;
+        text.push('\n');
+        fs::write(path, text)
+    }
+
+    // Carica il binario indicato dal progetto, il percorso è relativo al
+    // file di progetto. Il CRC deve corrispondere a quello salvato.
+    pub fn load_binary(&self) -> io::Result<BinaryBuffer> {
+        let bytes = fs::read(self.base_dir.join(&self.binary))?;
+        let crc = crc32(&bytes);
+        if crc != self.crc32 {
+            return Err(io::Error::new(io::ErrorKind::InvalidData,
+                format!("{}: CRC32 {:08X} does not match project ({:08X})", self.binary, crc, self.crc32)));
+        }
+        Ok(BinaryBuffer::new(bytes, self.load_address))
+    }
+}
+
+impl Dasm {
+    pub fn set_label(&mut self, address: u32, name: &str) {
+        self.user_labels.insert(address, name.to_owned());
+        self.labels_map.insert(address, name.to_owned());
+    }
+
+    pub fn set_comment(&mut self, address: u32, text: &str) {
+        self.user_comments.insert(address, text.to_owned());
+    }
+
+    // Il progetto vale solo per il binario da cui è stato creato.
+    pub fn apply_project(&mut self, project: &Project) -> io::Result<()> {
+        let memory = self.cpu.get_memory();
+        let crc = crc32(memory.get_data());
+        if crc != project.crc32 || memory.get_loaded_address() != project.load_address {
+            return Err(io::Error::new(io::ErrorKind::InvalidData,
+                format!("{}: binary CRC32 {:08X} at ${:04X} does not match project ({:08X} at ${:04X})",
+                    project.binary, crc, memory.get_loaded_address(), project.crc32, project.load_address)));
+        }
+
+        if let Some(platform) = project.platform {
+            self.set_platform(platform);
+        }
+        for label in &project.labels {
+            self.set_label(label.address, &label.name);
+        }
+        for comment in &project.comments {
+            self.set_comment(comment.address, &comment.text);
+        }
+        for region in &project.regions {
+            self.add_data_region(*region);
+        }
+        self.banking = project.banking.clone();
+
+        self.user_entry_points = project.entry_points.clone();
+        self.entry_points = self.user_entry_points.clone();
+        self.start_from_entry_points();
+        Ok(())
+    }
+
+    // Crea un progetto con lo stato corrente. Le label, le regioni e i
+    // punti di ingresso trovati dall'analisi non vengono salvati.
+    pub fn to_project(&self, binary: &str) -> Project {
+        let mut project = Project::new(binary, self.cpu.get_memory());
+
+        project.platform = self.platform;
+        project.entry_points = self.user_entry_points.clone();
+        project.labels = self.user_labels.iter()
+            .map(|(address, name)| LabelEntry { address: *address, name: name.clone() })
+            .collect();
+        project.labels.sort_by_key(|l| l.address);
+        project.comments = self.user_comments.iter()
+            .map(|(address, text)| CommentEntry { address: *address, text: text.clone() })
+            .collect();
+        project.comments.sort_by_key(|c| c.address);
+        project.regions = self.user_regions.clone();
+        project.banking = self.banking.clone();
+        project
+    }
+}
+
+pub(crate) mod hex_address {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Raw {
+        Number(u32),
+        Text(String)
+    }
+
+    pub fn parse(text: &str) -> Option<u32> {
+        let text = text.trim();
+        let digits = text.strip_prefix('$')
+            .or_else(|| text.strip_prefix("0x"))
+            .or_else(|| text.strip_prefix("0X"));
+        match digits {
+            Some(hex) => u32::from_str_radix(hex, 16).ok(),
+            None => text.parse().ok()
+        }
+    }
+
+    pub fn serialize<S: Serializer>(address: &u32, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("${:04X}", address))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+        match Raw::deserialize(deserializer)? {
+            Raw::Number(n) => Ok(n),
+            Raw::Text(text) => parse(&text).ok_or_else(|| D::Error::custom(format!("invalid address: {}", text)))
+        }
+    }
+
+    pub mod list {
+        use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+        #[derive(Serialize, Deserialize)]
+        struct Address(#[serde(with = "super")] u32);
+
+        pub fn serialize<S: Serializer>(addresses: &[u32], serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(addresses.iter().map(|a| Address(*a)))
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u32>, D::Error> {
+            Ok(Vec::<Address>::deserialize(deserializer)?.into_iter().map(|a| a.0).collect())
+        }
+    }
+}
+
+mod hex_crc {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(crc: &u32, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("{:08X}", crc))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        u32::from_str_radix(&text, 16).map_err(|_| D::Error::custom(format!("invalid CRC32: {}", text)))
+    }
+}
@@ -0,0 +1,318 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::{petscii_to_ascii, screen_code_to_ascii};
+use super::{project::hex_address, types::{DisassembledLine, LineType}, Dasm, DATA_FLAG, JMP_OPCODE};
+
+pub(super) const BYTES_PER_LINE: u32 = 8;
+const CHARS_PER_LINE: u32 = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RegionType {
+    Bytes,
+    Words,
+    // Tabella di word che puntano ad altri indirizzi, genera le label.
+    Pointers,
+    // Puntatori per il dispatch con RTS, ogni word vale destinazione-1.
+    RtsPointers,
+    // Tabelle di puntatori divise in byte bassi e alti, rts come sopra.
+    LoPointers {
+        #[serde(with = "hex_address")]
+        hi_table: u32,
+        #[serde(default, skip_serializing_if = "is_false")]
+        rts: bool
+    },
+    HiPointers {
+        #[serde(with = "hex_address")]
+        lo_table: u32,
+        #[serde(default, skip_serializing_if = "is_false")]
+        rts: bool
+    },
+    Petscii,
+    ScreenCodes,
+    // Stringhe PETSCII chiuse dal bit alto sull'ultimo carattere (.shift)
+    // o da uno zero (.null).
+    Shifted,
+    NullTerminated,
+    Fill,
+}
+
+fn is_false(value: &bool) -> bool {
+    !value
+}
+
+impl RegionType {
+    // Le direttive seguono la sintassi di 64tass. I codici schermo non
+    // hanno una direttiva senza cambiare codifica con .enc, per cui
+    // restano byte con il testo nel commento.
+    pub fn directive(&self) -> &'static str {
+        match self {
+            RegionType::Bytes |
+            RegionType::ScreenCodes |
+            RegionType::LoPointers { .. } |
+            RegionType::HiPointers { .. } => ".byte",
+            RegionType::Words |
+            RegionType::Pointers |
+            RegionType::RtsPointers => ".word",
+            RegionType::Petscii => ".text",
+            RegionType::Shifted => ".shift",
+            RegionType::NullTerminated => ".null",
+            RegionType::Fill => ".fill",
+        }
+    }
+}
+
+// Intervallo di indirizzi assoluti, estremi inclusi.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct DataRegion {
+    #[serde(with = "hex_address")]
+    pub start: u32,
+    #[serde(with = "hex_address")]
+    pub end: u32,
+    #[serde(flatten)]
+    pub region_type: RegionType
+}
+
+impl DataRegion {
+    pub fn new(start: u32, end: u32, region_type: RegionType) -> Self {
+        DataRegion {
+            start,
+            end,
+            region_type
+        }
+    }
+
+    pub fn contains(&self, address: u32) -> bool {
+        address >= self.start && address <= self.end
+    }
+
+    pub fn len(&self) -> u32 {
+        self.end - self.start + 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end < self.start
+    }
+}
+
+impl Dasm {
+    pub fn add_data_region(&mut self, region: DataRegion) {
+        self.user_regions.push(region);
+        self.user_regions.sort_by_key(|r| r.start);
+        self.push_region(region);
+    }
+
+    // Regione trovata dall'analisi: vale solo fino al prossimo disassemble
+    // e non finisce nel progetto.
+    pub(super) fn push_region(&mut self, region: DataRegion) {
+        self.regions.push(region);
+        self.regions.sort_by_key(|r| r.start);
+    }
+
+    pub fn add_split_pointer_table(&mut self, lo_table: u32, hi_table: u32, count: u32) {
+        if count == 0 {
+            return;
+        }
+        self.add_data_region(DataRegion::new(lo_table, lo_table + count - 1, RegionType::LoPointers { hi_table, rts: false }));
+        self.add_data_region(DataRegion::new(hi_table, hi_table + count - 1, RegionType::HiPointers { lo_table, rts: false }));
+    }
+
+    // Regioni dell'utente e quelle trovate dall'analisi.
+    pub fn get_regions(&self) -> &[DataRegion] {
+        &self.regions
+    }
+
+    pub(super) fn region_at(&self, address: u32) -> Option<DataRegion> {
+        self.regions.iter().find(|r| r.contains(address)).copied()
+    }
+
+    pub(super) fn next_region_start(&self, address: u32) -> Option<u32> {
+        self.regions.iter().map(|r| r.start).find(|start| *start > address)
+    }
+
+    // Emette le righe per la parte della regione compresa nel buffer
+    // a partire da 'from', e ritorna l'indirizzo successivo alla regione.
+    pub(super) fn disassemble_region(&mut self, region: DataRegion, from: u32) -> u32 {
+        let memory = self.cpu.get_memory();
+        let buffer_end = memory.get_loaded_address() + memory.get_size() as u32;
+        let end = region.end.min(buffer_end - 1);
+        let mut address = from;
+
+        while address <= end {
+            let remaining = end - address + 1;
+            let line = match region.region_type {
+                RegionType::Bytes => self.bytes_line(address, remaining.min(BYTES_PER_LINE)),
+                RegionType::Words => {
+                    if remaining < 2 {
+                        self.bytes_line(address, remaining)
+                    }else{
+                        self.words_line(address, (remaining / 2).min(BYTES_PER_LINE / 2))
+                    }
+                },
+                RegionType::Pointers |
+                RegionType::RtsPointers => {
+                    if remaining < 2 {
+                        self.bytes_line(address, remaining)
+                    }else{
+                        self.pointer_line(address, region.region_type == RegionType::RtsPointers)
+                    }
+                },
+                RegionType::LoPointers { hi_table, rts } => self.split_pointer_line(address, address, hi_table + (address - region.start), "<", rts),
+                RegionType::HiPointers { lo_table, rts } => self.split_pointer_line(address, lo_table + (address - region.start), address, ">", rts),
+                RegionType::Petscii => self.text_line(address, remaining.min(CHARS_PER_LINE), region.region_type, petscii_to_ascii),
+                RegionType::ScreenCodes => self.screen_code_line(address, remaining.min(BYTES_PER_LINE)),
+                RegionType::Shifted |
+                RegionType::NullTerminated => self.terminated_text_line(address, remaining, region.region_type),
+                RegionType::Fill => self.fill_line(address, remaining),
+            };
+
+            address += line.size();
+            self.lines.push(line);
+        }
+
+        region.end + 1
+    }
+
+    fn read_data_byte(&self, address: u32) -> Option<u8> {
+        let memory = self.cpu.get_memory();
+        memory.to_offset(address).map(|offset| memory.read_byte(offset))
+    }
+
+    fn data_line(&self, address: u32, size: u32, directive: &str) -> DisassembledLine {
+        let mut line = DisassembledLine::new();
+        line.line_type = LineType::Data;
+        line.flags = DATA_FLAG;
+        line.address = address;
+        line.instr_size = (size - 1) as u8;
+        line.opcode = directive.to_owned();
+        for i in 0..size {
+            line.byte_code[i as usize] = self.read_data_byte(address + i).unwrap_or(0);
+        }
+        line
+    }
+
+    pub(super) fn bytes_line(&self, address: u32, size: u32) -> DisassembledLine {
+        let mut line = self.data_line(address, size, RegionType::Bytes.directive());
+        line.operand = line.byte_code[..size as usize].iter()
+            .map(|b| format!("${:02X}", b))
+            .collect::<Vec<String>>()
+            .join(",");
+        line
+    }
+
+    fn words_line(&self, address: u32, count: u32) -> DisassembledLine {
+        let mut line = self.data_line(address, count * 2, RegionType::Words.directive());
+        line.operand = line.byte_code[..(count * 2) as usize].chunks(2)
+            .map(|w| format!("${:02X}{:02X}", w[1], w[0]))
+            .collect::<Vec<String>>()
+            .join(",");
+        line
+    }
+
+    // Label del puntatore: le destinazioni già note come codice hanno
+    // una label di salto, le altre una label di dati.
+    fn pointer_label(&mut self, target: u32) -> String {
+        let flags = if self.entry_points.contains(&target) { JMP_OPCODE } else { DATA_FLAG };
+        self.label_for(target, flags)
+    }
+
+    fn pointer_line(&mut self, address: u32, rts: bool) -> DisassembledLine {
+        let mut line = self.data_line(address, 2, RegionType::Pointers.directive());
+        let value = (line.byte_code[1] as u32) << 8 | line.byte_code[0] as u32;
+        line.address_ref = if rts { value + 1 } else { value };
+        line.address_text = format!("${:04X}", value);
+        line.operand = self.pointer_label(line.address_ref);
+        if rts {
+            line.operand.push_str("-1");
+        }
+        line.comment = format!("${:04X}", line.address_ref);
+        line
+    }
+
+    fn split_pointer_line(&mut self, address: u32, lo_address: u32, hi_address: u32, operator: &str, rts: bool) -> DisassembledLine {
+        let mut line = self.data_line(address, 1, RegionType::Bytes.directive());
+        match (self.read_data_byte(lo_address), self.read_data_byte(hi_address)) {
+            (Some(lo), Some(hi)) => {
+                let value = (hi as u32) << 8 | lo as u32;
+                line.address_ref = if rts { value + 1 } else { value };
+                line.address_text = format!("${:04X}", value);
+                let label = self.pointer_label(line.address_ref);
+                line.operand = if rts { format!("{}({}-1)", operator, label) } else { format!("{}{}", operator, label) };
+                line.comment = format!("${:04X}", line.address_ref);
+            },
+            _ => line.operand = format!("${:02X}", line.byte_code[0])
+        }
+        line
+    }
+
+    fn text_line(&self, address: u32, size: u32, region_type: RegionType, to_char: fn(u8) -> Option<char>) -> DisassembledLine {
+        let mut line = self.data_line(address, size, region_type.directive());
+        let mut parts: Vec<String> = Vec::new();
+        let mut quoted = String::new();
+
+        for byte in &line.byte_code[..size as usize] {
+            match to_char(*byte) {
+                Some(c) => quoted.push(c),
+                None => {
+                    if !quoted.is_empty() {
+                        parts.push(format!("\"{}\"", quoted));
+                        quoted.clear();
+                    }
+                    parts.push(format!("${:02X}", byte));
+                }
+            }
+        }
+        if !quoted.is_empty() {
+            parts.push(format!("\"{}\"", quoted));
+        }
+
+        line.operand = parts.join(",");
+        line
+    }
+
+    fn screen_code_line(&self, address: u32, size: u32) -> DisassembledLine {
+        let mut line = self.bytes_line(address, size);
+        let text: String = line.byte_code[..size as usize].iter().map(|b| screen_code_to_ascii(*b).unwrap_or('.')).collect();
+        line.comment = format!("\"{}\"", text);
+        line
+    }
+
+    // Una riga per stringa: se non sta in una riga i primi caratteri
+    // vanno in righe .text e l'ultima riga chiude la stringa.
+    fn terminated_text_line(&self, address: u32, remaining: u32, region_type: RegionType) -> DisassembledLine {
+        let is_terminator = |byte: u8| if region_type == RegionType::Shifted { byte & 0x80 != 0 } else { byte == 0 };
+        let length = (0..remaining).find(|i| self.read_data_byte(address + i).is_some_and(is_terminator)).map(|i| i + 1);
+        let Some(length) = length else {
+            return self.text_line(address, remaining.min(CHARS_PER_LINE), RegionType::Petscii, petscii_to_ascii);
+        };
+        if length > CHARS_PER_LINE {
+            return self.text_line(address, (length - 1).min(CHARS_PER_LINE), RegionType::Petscii, petscii_to_ascii);
+        }
+
+        let mut line = self.data_line(address, length, region_type.directive());
+        let characters = match region_type {
+            RegionType::Shifted => &line.byte_code[..length as usize],
+            _ => &line.byte_code[..length as usize - 1]
+        };
+        let text: Option<String> = characters.iter().map(|b| petscii_to_ascii(b & 0x7F)).collect();
+        match text {
+            Some(text) => line.operand = format!("\"{}\"", text),
+            None => return self.text_line(address, length, RegionType::Petscii, petscii_to_ascii)
+        }
+        line
+    }
+
+    // Una riga .fill copre l'intera sequenza di byte uguali.
+    fn fill_line(&self, address: u32, remaining: u32) -> DisassembledLine {
+        let value = self.read_data_byte(address).unwrap_or(0);
+        let mut count = 1;
+        while count < remaining && self.read_data_byte(address + count) == Some(value) {
+            count += 1;
+        }
+
+        let mut line = self.data_line(address, 1, RegionType::Fill.directive());
+        line.repeat_count = count;
+        line.operand = format!("{},${:02X}", count, value);
+        line
+    }
+}
@@ -0,0 +1,44 @@
+use std::collections::BTreeMap;
+
+use super::{types::LineType, Dasm, SELF_MODIFIED_FLAG, STORE_FLAG};
+
+impl Dasm {
+    // Cerca le store che scrivono dentro un'istruzione decodificata: la
+    // store viene espressa come label+n e l'istruzione modificata riceve
+    // il flag e un commento con chi la modifica.
+    pub(super) fn detect_self_modifying_code(&mut self) {
+        let mut patches: BTreeMap<usize, Vec<u32>> = BTreeMap::new();
+
+        for index in 0..self.lines.len() {
+            let line = &self.lines[index];
+            // Con (zp),Y e (zp,X) l'operando è il puntatore, non la destinazione.
+            if line.line_type != LineType::Instruction || line.flags & STORE_FLAG == 0 || !line.has_reference() || line.operand.starts_with('(') {
+                continue;
+            }
+            let Some(patched) = self.line_containing(line.address_ref) else {
+                continue;
+            };
+            if self.lines[patched].line_type != LineType::Instruction {
+                continue;
+            }
+
+            let target = self.lines[patched].address;
+            let label = self.label_for(target, SELF_MODIFIED_FLAG);
+            let line = &mut self.lines[index];
+            let offset = line.address_ref - target;
+            let expression = if offset == 0 { label } else { format!("{}+{}", label, offset) };
+            line.operand = line.operand.replacen(&line.address_text, &expression, 1);
+
+            patches.entry(patched).or_default().push(line.address);
+        }
+
+        for (index, stores) in patches {
+            let patched = &mut self.lines[index];
+            let sources = stores.iter().map(|a| format!("${:04X}", a)).collect::<Vec<String>>().join(", ");
+            let note = format!("dynamic operand, patched by {}", sources);
+
+            patched.flags |= SELF_MODIFIED_FLAG;
+            patched.comment = if patched.comment.is_empty() { note } else { format!("{}  {}", patched.comment, note) };
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::utils::{find_strings, Terminator, TextEncoding};
+use super::{regions::{DataRegion, RegionType}, Dasm};
+
+impl Dasm {
+    // Abilita la ricerca delle stringhe in disassemble(), con la lunghezza
+    // minima in caratteri.
+    pub fn set_string_detection(&mut self, min_chars: Option<usize>) {
+        self.string_min_chars = min_chars;
+    }
+
+    // Cerca stringhe nell'immagine e le aggiunge come regioni di testo.
+    // Vengono saltate quelle che toccano regioni esistenti, punti di
+    // ingresso o codice eseguito durante un trace. Ritorna quante ne ha
+    // aggiunte.
+    pub(super) fn detect_strings(&mut self, min_chars: usize) -> usize {
+        let memory = self.cpu.get_memory();
+        let loaded_address = memory.get_loaded_address();
+        let start_address = self.start_pc + loaded_address;
+        let candidates = find_strings(memory.get_data(), min_chars);
+        let mut added = 0;
+
+        for candidate in candidates {
+            let start = loaded_address + candidate.offset;
+            let end = start + candidate.length - 1;
+            let claimed = (start..=end).any(|a| {
+                a == start_address
+                    || self.region_at(a).is_some()
+                    || self.entry_points.contains(&a)
+                    || self.coverage.as_ref().is_some_and(|c| c.is_code_byte(a))
+            });
+            if claimed {
+                continue;
+            }
+
+            let region_type = match (candidate.encoding, candidate.terminator) {
+                (TextEncoding::Petscii, Terminator::HighBit) => RegionType::Shifted,
+                (TextEncoding::Petscii, Terminator::Zero) => RegionType::NullTerminated,
+                (TextEncoding::Petscii, Terminator::None) => RegionType::Petscii,
+                (TextEncoding::ScreenCodes, _) => RegionType::ScreenCodes,
+            };
+            self.push_region(DataRegion::new(start, end, region_type));
+            added += 1;
+        }
+
+        added
+    }
+}
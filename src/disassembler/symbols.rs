@@ -0,0 +1,225 @@
+use std::{collections::BTreeSet, fmt::Write, fs, io, path::Path};
+use serde::Serialize;
+
+use super::{project::hex_address, Dasm};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SymbolFormat {
+    // "al C:a000 .label", anche i file .lbl generati da ld65 -Ln
+    Vice,
+    // "label = $a000", symbol list di ACME e 64tass
+    Acme,
+    // righe "sym" dei file .dbg di ld65
+    Ca65Dbg,
+    // ".label name=$a000" dei file .sym di KickAssembler
+    KickAssembler,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Symbol {
+    pub address: u32,
+    pub name: String
+}
+
+impl SymbolFormat {
+    // Riconosce il formato dalla prima riga significativa.
+    pub fn detect(text: &str) -> Option<Self> {
+        for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            if line.starts_with("al ") {
+                return Some(SymbolFormat::Vice);
+            }else if line.starts_with(".label ") || line.starts_with(".const ") || line.starts_with(".namespace ") {
+                return Some(SymbolFormat::KickAssembler);
+            }else if line.starts_with("version\t") || line.starts_with("info\t") || line.starts_with("sym\t") {
+                return Some(SymbolFormat::Ca65Dbg);
+            }else if line.contains('=') {
+                return Some(SymbolFormat::Acme);
+            }
+        }
+        None
+    }
+
+    pub fn parse(&self, text: &str) -> Vec<Symbol> {
+        text.lines()
+            .map(str::trim)
+            .filter_map(|line| match self {
+                SymbolFormat::Vice => parse_vice(line),
+                SymbolFormat::Acme => parse_acme(line),
+                SymbolFormat::Ca65Dbg => parse_ca65_dbg(line),
+                SymbolFormat::KickAssembler => parse_kickassembler(line),
+            })
+            .collect()
+    }
+}
+
+fn parse_vice(line: &str) -> Option<Symbol> {
+    let mut fields = line.split_whitespace();
+    if fields.next()? != "al" {
+        return None;
+    }
+    // Lo spazio di memoria "C:" è opzionale.
+    let address = fields.next()?;
+    let address = address.split_once(':').map_or(address, |(_, a)| a);
+    let name = fields.next()?.trim_start_matches('.');
+
+    Some(Symbol {
+        address: u32::from_str_radix(address, 16).ok()?,
+        name: name.to_owned()
+    })
+}
+
+fn parse_acme(line: &str) -> Option<Symbol> {
+    let line = line.split(';').next()?;
+    let (name, value) = line.split_once('=')?;
+    let name = name.trim();
+    if name.is_empty() || name.contains(char::is_whitespace) {
+        return None;
+    }
+
+    // Come per KickAssembler si tengono solo gli indirizzi: le costanti
+    // come "count = 12" non hanno la forma esadecimale.
+    let value = value.trim();
+    if !value.starts_with('$') && !value.to_ascii_lowercase().starts_with("0x") {
+        return None;
+    }
+    let address = hex_address::parse(value).filter(|a| *a <= 0xFFFF)?;
+
+    Some(Symbol {
+        address,
+        name: name.to_owned()
+    })
+}
+
+fn parse_ca65_dbg(line: &str) -> Option<Symbol> {
+    let attributes = line.strip_prefix("sym\t")?;
+    let mut name = None;
+    let mut value = None;
+    let mut is_label = false;
+
+    for attribute in attributes.split(',') {
+        match attribute.split_once('=')? {
+            ("name", n) => name = Some(n.trim_matches('"')),
+            ("val", v) => value = hex_address::parse(v),
+            ("type", t) => is_label = t == "lab",
+            _ => {}
+        }
+    }
+
+    if !is_label {
+        return None;
+    }
+    Some(Symbol {
+        address: value?,
+        name: name?.to_owned()
+    })
+}
+
+fn parse_kickassembler(line: &str) -> Option<Symbol> {
+    let (name, value) = line.strip_prefix(".label ")?.split_once('=')?;
+
+    Some(Symbol {
+        address: hex_address::parse(value)?,
+        name: name.trim().to_owned()
+    })
+}
+
+impl Dasm {
+    // Le label importate sostituiscono quelle generate e vengono salvate
+    // nel progetto come label utente.
+    pub fn import_symbols(&mut self, symbols: &[Symbol]) {
+        for symbol in symbols {
+            self.set_label(symbol.address, &symbol.name);
+        }
+    }
+
+    pub fn import_symbol_file<P: AsRef<Path>>(&mut self, path: P, format: Option<SymbolFormat>) -> io::Result<usize> {
+        let text = fs::read_to_string(&path)?;
+        let format = format.or_else(|| SymbolFormat::detect(&text))
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData,
+                format!("{}: unknown symbol file format", path.as_ref().display())))?;
+
+        let symbols = format.parse(&text);
+        self.import_symbols(&symbols);
+        Ok(symbols.len())
+    }
+}
+
+// Riga della tabella dei simboli esportata in CSV e JSON.
+#[derive(Debug, Serialize)]
+struct SymbolRow<'a> {
+    #[serde(with = "hex_address")]
+    address: u32,
+    name: &'a str,
+    comment: &'a str
+}
+
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    }else{
+        text.to_owned()
+    }
+}
+
+impl Dasm {
+    // Tutte le label note, generate o dell'utente, ordinate per indirizzo.
+    pub fn get_symbols(&self) -> Vec<Symbol> {
+        let mut symbols: Vec<Symbol> = self.labels_map.iter()
+            .map(|(address, name)| Symbol { address: *address, name: name.clone() })
+            .collect();
+        symbols.sort_by_key(|s| s.address);
+        symbols
+    }
+
+    fn symbol_rows(&self) -> Vec<SymbolRow<'_>> {
+        let addresses: BTreeSet<u32> = self.labels_map.keys().chain(self.user_comments.keys()).copied().collect();
+
+        addresses.into_iter().map(|address| SymbolRow {
+            address,
+            name: self.labels_map.get(&address).map_or("", String::as_str),
+            comment: self.user_comments.get(&address).map_or("", String::as_str)
+        }).collect()
+    }
+
+    // File di label per VICE, caricabile con "ll" o -moncommands.
+    pub fn export_vice_labels(&self) -> String {
+        let mut output = String::new();
+        for symbol in self.get_symbols() {
+            writeln!(output, "al C:{:04x} .{}", symbol.address, symbol.name).unwrap();
+        }
+        output
+    }
+
+    // Script di comandi per il monitor di VICE: label, commenti e un
+    // breakpoint per ogni indirizzo richiesto. Le label senza breakpoint
+    // hanno il comando break commentato, pronto da abilitare.
+    pub fn export_vice_monitor(&self, breakpoints: &[u32]) -> String {
+        let mut output = self.export_vice_labels();
+
+        for row in self.symbol_rows().iter().filter(|r| !r.comment.is_empty()) {
+            writeln!(output, "; ${:04X}: {}", row.address, row.comment).unwrap();
+        }
+
+        for symbol in self.get_symbols() {
+            let prefix = if breakpoints.contains(&symbol.address) { "" } else { "; " };
+            writeln!(output, "{}break .{}", prefix, symbol.name).unwrap();
+        }
+        for address in breakpoints.iter().filter(|a| !self.labels_map.contains_key(a)) {
+            writeln!(output, "break ${:04x}", address).unwrap();
+        }
+        output
+    }
+
+    pub fn export_symbols_csv(&self) -> String {
+        let mut output = String::from("address,name,comment\n");
+        for row in self.symbol_rows() {
+            writeln!(output, "${:04X},{},{}", row.address, csv_field(row.name), csv_field(row.comment)).unwrap();
+        }
+        output
+    }
+
+    pub fn export_symbols_json(&self) -> String {
+        let mut output = serde_json::to_string_pretty(&self.symbol_rows()).unwrap();
+        output.push('\n');
+        output
+    }
+}
@@ -0,0 +1,860 @@
+use crate::{cpus::mos6510::{emulator::Emulator6510, Cpu6510}, memory::BinaryBuffer, platforms::{BitField, IoRegister, Platform}};
+use super::{cfg::{Edge, EdgeKind}, classify::{Confidence, GapKind}, graphs::GraphFormat, project::Project, regions::{DataRegion, RegionType}, symbols::{Symbol, SymbolFormat}, types::LineType, xref::{Xref, XrefKind}, zeropage::ZeroPageUsage, Dasm, SELF_MODIFIED_FLAG};
+
+const LOAD_ADDRESS: u32 = 0xC000;
+
+fn dasm_with(bytes: &[u8]) -> Dasm {
+    let memory = BinaryBuffer::new(bytes.to_vec(), LOAD_ADDRESS);
+    Dasm::new(Box::new(Cpu6510::new(memory)), 0)
+}
+
+fn operands(dasm: &Dasm) -> Vec<(String, String)> {
+    dasm.get_lines().iter().map(|l| (l.opcode.clone(), l.operand.clone())).collect()
+}
+
+#[test]
+fn branch_labels_are_keyed_on_target() {
+    // C000 JSR $C006 / C003 JMP $C006 / C006 RTS
+    let mut dasm = dasm_with(&[0x20, 0x06, 0xC0, 0x4C, 0x06, 0xC0, 0x60]);
+    dasm.disassemble();
+
+    let lines = dasm.get_lines();
+    assert_eq!(lines[0].operand, "SUBRT00000000");
+    assert_eq!(lines[1].operand, "SUBRT00000000");
+    assert_eq!(dasm.get_label(0xC006).unwrap(), "SUBRT00000000");
+    assert!(dasm.get_label(0xC000).is_none());
+}
+
+#[test]
+fn data_regions_render_directives() {
+    let mut bytes = vec![0xEA];
+    bytes.extend_from_slice(&[0x01, 0x02, 0x03]);          // C001 bytes
+    bytes.extend_from_slice(&[0x34, 0x12]);                // C004 words
+    bytes.extend_from_slice(&[0x00, 0xC0]);                // C006 pointers
+    bytes.extend_from_slice(&[0x48, 0x49, 0x0D]);          // C008 petscii
+    bytes.extend_from_slice(&[0x08, 0x09]);                // C00B screen codes
+    bytes.extend_from_slice(&[0xAA, 0xAA, 0xAA, 0xAA]);    // C00D fill
+    bytes.push(0x60);
+
+    let mut dasm = dasm_with(&bytes);
+    dasm.add_data_region(DataRegion::new(0xC001, 0xC003, RegionType::Bytes));
+    dasm.add_data_region(DataRegion::new(0xC004, 0xC005, RegionType::Words));
+    dasm.add_data_region(DataRegion::new(0xC006, 0xC007, RegionType::Pointers));
+    dasm.add_data_region(DataRegion::new(0xC008, 0xC00A, RegionType::Petscii));
+    dasm.add_data_region(DataRegion::new(0xC00B, 0xC00C, RegionType::ScreenCodes));
+    dasm.add_data_region(DataRegion::new(0xC00D, 0xC010, RegionType::Fill));
+    dasm.disassemble();
+
+    let expected: Vec<(String, String)> = [
+        ("NOP", ""),
+        (".byte", "$01,$02,$03"),
+        (".word", "$1234"),
+        (".word", "PNTR_00000000"),
+        (".text", "\"HI\",$0D"),
+        (".byte", "$08,$09"),
+        (".fill", "4,$AA"),
+        ("RTS", ""),
+    ].iter().map(|(o, p)| (o.to_string(), p.to_string())).collect();
+
+    assert_eq!(operands(&dasm), expected);
+    assert_eq!(dasm.get_lines()[1].line_type, LineType::Data);
+    assert_eq!(dasm.get_lines()[5].comment, "\"HI\"");
+    assert_eq!(dasm.get_label(0xC000).unwrap(), "PNTR_00000000");
+}
+
+#[test]
+fn split_pointer_tables_pair_lo_and_hi() {
+    // C000 RTS, lo table at C001, hi table at C003
+    let mut dasm = dasm_with(&[0x60, 0x00, 0x10, 0xC0, 0xC0]);
+    dasm.add_split_pointer_table(0xC001, 0xC003, 2);
+    dasm.disassemble();
+
+    let ops = operands(&dasm);
+    assert_eq!(ops[1].1, "<PNTR_00000000");
+    assert_eq!(ops[2].1, "<PNTR_00000001");
+    assert_eq!(ops[3].1, ">PNTR_00000000");
+    assert_eq!(ops[4].1, ">PNTR_00000001");
+    assert_eq!(dasm.get_label(0xC010).unwrap(), "PNTR_00000001");
+
+    dasm.add_split_pointer_table(0, 0xC003, 0);
+    assert_eq!(dasm.get_regions().len(), 2);
+}
+
+#[test]
+fn instruction_overlapping_a_region_becomes_bytes() {
+    // LDA $C003 would swallow the start of the region at C002
+    let mut dasm = dasm_with(&[0xAD, 0x03, 0xC0, 0x60]);
+    dasm.add_data_region(DataRegion::new(0xC002, 0xC002, RegionType::Bytes));
+    dasm.disassemble();
+
+    assert_eq!(operands(&dasm)[0], (".byte".to_string(), "$AD,$03".to_string()));
+    assert_eq!(dasm.get_lines()[1].address, 0xC002);
+    assert_eq!(dasm.get_lines()[2].opcode, "RTS");
+}
+
+#[test]
+fn project_round_trip() {
+    let mut dasm = dasm_with(&[0x20, 0x06, 0xC0, 0xEA, 0x01, 0x02, 0x60]);
+    dasm.set_label(0xC006, "print_char");
+    dasm.set_comment(0xC003, "padding");
+    dasm.add_data_region(DataRegion::new(0xC004, 0xC005, RegionType::Bytes));
+    dasm.add_split_pointer_table(0xC100, 0xC110, 4);
+
+    let project = dasm.to_project("prg.bin");
+    let json = serde_json::to_string(&project).unwrap();
+    assert!(json.contains("\"load_address\":\"$C000\""));
+    assert!(json.contains("\"type\":\"lo_pointers\",\"hi_table\":\"$C110\""));
+
+    let loaded: Project = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded.crc32, project.crc32);
+    assert_eq!(loaded.labels[0].name, "print_char");
+    assert_eq!(loaded.regions.len(), 3);
+
+    let mut other = dasm_with(&[0x20, 0x06, 0xC0, 0xEA, 0x01, 0x02, 0x00]);
+    assert!(other.apply_project(&loaded).is_err());
+
+    let mut fresh = dasm_with(&[0x20, 0x06, 0xC0, 0xEA, 0x01, 0x02, 0x60]);
+    fresh.apply_project(&loaded).unwrap();
+    fresh.disassemble();
+
+    let lines = fresh.get_lines();
+    assert_eq!(lines[0].operand, "print_char");
+    assert_eq!(lines[1].comment, "padding");
+    assert_eq!(lines[2].opcode, ".byte");
+}
+
+#[test]
+fn project_accepts_hand_written_addresses() {
+    let json = r#"{
+        "version": 1,
+        "binary": "rom.bin",
+        "crc32": "CBF43926",
+        "load_address": "0xA000",
+        "entry_points": ["$A000", 41000],
+        "regions": [ { "start": "$A000", "end": "$A00B", "type": "pointers" } ]
+    }"#;
+
+    let project: Project = serde_json::from_str(json).unwrap();
+    assert_eq!(project.load_address, 0xA000);
+    assert_eq!(project.entry_points, vec![0xA000, 41000]);
+    assert_eq!(project.regions[0].region_type, RegionType::Pointers);
+    assert!(project.labels.is_empty());
+}
+
+fn symbol(address: u32, name: &str) -> Symbol {
+    Symbol { address, name: name.to_owned() }
+}
+
+#[test]
+fn vice_symbols() {
+    let text = "al C:a000 .basic_cold\nal 00FFD2 .chrout\n";
+    assert_eq!(SymbolFormat::detect(text), Some(SymbolFormat::Vice));
+    assert_eq!(SymbolFormat::Vice.parse(text), vec![symbol(0xA000, "basic_cold"), symbol(0xFFD2, "chrout")]);
+}
+
+#[test]
+fn acme_and_64tass_symbols() {
+    let text = "\tchrout\t= $ffd2\t; ?\nscreen = $0400\ncount = 12\nbig = $12345\ncolour = 0x0e\n";
+    assert_eq!(SymbolFormat::detect(text), Some(SymbolFormat::Acme));
+    assert_eq!(SymbolFormat::Acme.parse(text), vec![symbol(0xFFD2, "chrout"), symbol(0x0400, "screen"), symbol(0x0E, "colour")]);
+}
+
+#[test]
+fn ca65_dbg_symbols() {
+    let text = "version\tmajor=2,minor=0\n\
+sym\tid=0,name=\"main\",addrsize=absolute,scope=0,def=1,val=0x80D,seg=0,type=lab\n\
+sym\tid=1,name=\"WIDTH\",addrsize=zeropage,scope=0,def=2,val=0x28,type=equ\n";
+    assert_eq!(SymbolFormat::detect(text), Some(SymbolFormat::Ca65Dbg));
+    assert_eq!(SymbolFormat::Ca65Dbg.parse(text), vec![symbol(0x080D, "main")]);
+}
+
+#[test]
+fn kickassembler_symbols() {
+    let text = ".namespace irq {\n.label start=$0810\n}\n.const WIDTH=40\n";
+    assert_eq!(SymbolFormat::detect(text), Some(SymbolFormat::KickAssembler));
+    assert_eq!(SymbolFormat::KickAssembler.parse(text), vec![symbol(0x0810, "start")]);
+}
+
+#[test]
+fn imported_symbols_replace_generated_labels() {
+    let mut dasm = dasm_with(&[0x20, 0x03, 0xC0, 0x60]);
+    dasm.import_symbols(&SymbolFormat::Vice.parse("al C:c003 .done"));
+    dasm.disassemble();
+
+    assert_eq!(dasm.get_lines()[0].operand, "done");
+    assert_eq!(dasm.to_project("prg.bin").labels[0].name, "done");
+}
+
+#[test]
+fn symbol_exports() {
+    let mut dasm = dasm_with(&[0x20, 0x03, 0xC0, 0x60]);
+    dasm.set_label(0xFFD2, "chrout");
+    dasm.set_comment(0xC000, "print, then return");
+    dasm.disassemble();
+
+    let labels = dasm.export_vice_labels();
+    assert_eq!(labels, "al C:c003 .SUBRT00000000\nal C:ffd2 .chrout\n");
+    assert_eq!(SymbolFormat::Vice.parse(&labels).len(), 2);
+
+    let monitor = dasm.export_vice_monitor(&[0xC003, 0xC000]);
+    assert!(monitor.contains("; $C000: print, then return\n"));
+    assert!(monitor.contains("\nbreak .SUBRT00000000\n"));
+    assert!(monitor.contains("; break .chrout\n"));
+    assert!(monitor.ends_with("break $c000\n"));
+
+    let csv = dasm.export_symbols_csv();
+    assert_eq!(csv.lines().nth(1), Some("$C000,,\"print, then return\""));
+    assert_eq!(csv.lines().nth(3), Some("$FFD2,chrout,"));
+
+    let json: serde_json::Value = serde_json::from_str(&dasm.export_symbols_json()).unwrap();
+    assert_eq!(json[2]["address"], "$FFD2");
+    assert_eq!(json[2]["name"], "chrout");
+}
+
+#[test]
+fn platform_symbols_replace_operands() {
+    // JSR $FFD2 / STA $D020 / LDA $90 / JMP ($0314) / LDA #$D0
+    let mut dasm = dasm_with(&[0x20, 0xD2, 0xFF, 0x8D, 0x20, 0xD0, 0xA5, 0x90, 0x6C, 0x14, 0x03, 0xA9, 0xD0]);
+    dasm.set_platform(Platform::C64);
+    dasm.set_label(0x90, "io_status");
+    dasm.disassemble();
+
+    let lines = dasm.get_lines();
+    assert_eq!(lines[0].operand, "CHROUT");
+    assert_eq!(lines[0].comment, "output character");
+    assert_eq!(lines[1].operand, "EXTCOL");
+    assert_eq!(lines[1].comment, "border colour");
+    assert_eq!(lines[2].operand, "io_status");
+    assert_eq!(lines[3].operand, "(CINV)");
+    assert_eq!(lines[4].operand, "#$D0");
+    assert!(dasm.export_vice_labels().contains("al C:ffd2 .CHROUT"));
+}
+
+#[test]
+fn xrefs_collect_every_reference() {
+    // C000 JSR $C00A / C003 BNE $C00A / C005 JMP $C00A / C008 .word $C00A / C00A LDA $C00A / C00D RTS
+    let mut dasm = dasm_with(&[0x20, 0x0A, 0xC0, 0xD0, 0x05, 0x4C, 0x0A, 0xC0, 0x0A, 0xC0, 0xAD, 0x0A, 0xC0, 0x60]);
+    dasm.add_data_region(DataRegion::new(0xC008, 0xC009, RegionType::Pointers));
+    dasm.disassemble();
+
+    let kinds: Vec<XrefKind> = dasm.get_xrefs(0xC00A).iter().map(|x| x.kind).collect();
+    assert_eq!(kinds[..4], [XrefKind::Call, XrefKind::Branch, XrefKind::Jump, XrefKind::Pointer]);
+    assert_eq!(dasm.get_xrefs(0xC00A)[4], Xref { from: 0xC00A, kind: XrefKind::Read });
+    assert_eq!(dasm.get_callers(0xC00A), vec![0xC000]);
+    assert_eq!(dasm.get_xrefs(0xC000), &[] as &[Xref]);
+    assert_eq!(dasm.get_all_xrefs().len(), 1);
+
+    let comments = dasm.xref_comments(0xC00A);
+    assert_eq!(comments.len(), 2);
+    assert_eq!(comments[0], "; xref: $C000 call, $C003 branch, $C005 jump, $C008 pointer");
+}
+
+#[test]
+fn xrefs_split_reads_and_writes() {
+    // LDA $C009 / STA $C009 / INC $C009 / RTS
+    let mut dasm = dasm_with(&[0xAD, 0x09, 0xC0, 0x8D, 0x09, 0xC0, 0xEE, 0x09, 0xC0, 0x60]);
+    dasm.disassemble();
+
+    assert_eq!(dasm.get_xrefs_of_kind(0xC009, XrefKind::Read), vec![0xC000]);
+    assert_eq!(dasm.get_xrefs_of_kind(0xC009, XrefKind::Write), vec![0xC003]);
+    assert_eq!(dasm.get_xrefs_of_kind(0xC009, XrefKind::ReadModifyWrite), vec![0xC006]);
+}
+
+#[test]
+fn control_flow_graph() {
+    // C000 JSR $C008 / C003 BNE $C000 / C005 JMP $C005
+    // C008 LDX #$00 / C00A BEQ $C00D / C00C INX / C00D RTS
+    let mut dasm = dasm_with(&[0x20, 0x08, 0xC0, 0xD0, 0xFB, 0x4C, 0x05, 0xC0, 0xA2, 0x00, 0xF0, 0x01, 0xE8, 0x60]);
+    dasm.disassemble();
+    let cfg = dasm.get_cfg();
+
+    let starts: Vec<u32> = cfg.blocks().map(|b| b.start).collect();
+    assert_eq!(starts, vec![0xC000, 0xC003, 0xC005, 0xC008, 0xC00C, 0xC00D]);
+    assert_eq!(cfg.block_containing(0xC00A).unwrap().start, 0xC008);
+    assert_eq!(cfg.block_at(0xC008).unwrap().last_line, 4);
+
+    let edge = |from, to, kind| Edge { from, to, kind };
+    let successors: Vec<Edge> = cfg.successors(0xC000).copied().collect();
+    assert_eq!(successors, vec![edge(0xC000, 0xC008, EdgeKind::Call), edge(0xC000, 0xC003, EdgeKind::FallThrough)]);
+    let successors: Vec<Edge> = cfg.successors(0xC008).copied().collect();
+    assert_eq!(successors, vec![edge(0xC008, 0xC00D, EdgeKind::Taken), edge(0xC008, 0xC00C, EdgeKind::FallThrough)]);
+    assert_eq!(cfg.successors(0xC005).count(), 1);
+    let returns: Vec<Edge> = cfg.successors(0xC00D).copied().collect();
+    assert_eq!(returns, vec![edge(0xC00D, 0xC003, EdgeKind::Return)]);
+    assert_eq!(cfg.predecessors(0xC000).count(), 1);
+
+    let main: Vec<u32> = cfg.function(0xC000).unwrap().blocks.iter().copied().collect();
+    assert_eq!(main, vec![0xC000, 0xC003, 0xC005]);
+    let subroutine: Vec<u32> = cfg.function(0xC008).unwrap().blocks.iter().copied().collect();
+    assert_eq!(subroutine, vec![0xC008, 0xC00C, 0xC00D]);
+    assert_eq!(cfg.functions().count(), 2);
+}
+
+#[test]
+fn brk_ends_a_basic_block() {
+    // C000 LDA #$01 / C002 BRK / C003 NOP
+    let mut dasm = dasm_with(&[0xA9, 0x01, 0x00, 0xEA]);
+    dasm.disassemble();
+    let cfg = dasm.get_cfg();
+
+    let starts: Vec<u32> = cfg.blocks().map(|b| b.start).collect();
+    assert_eq!(starts, vec![0xC000, 0xC003]);
+    assert_eq!(cfg.successors(0xC000).count(), 0);
+}
+
+#[test]
+fn graph_exports() {
+    // C000 JSR $C006 / C003 JMP $FFD2 / C006 BEQ $C009 / C008 INX / C009 RTS
+    let mut dasm = dasm_with(&[0x20, 0x06, 0xC0, 0x4C, 0xD2, 0xFF, 0xF0, 0x01, 0xE8, 0x60]);
+    dasm.set_platform(Platform::C64);
+    dasm.disassemble();
+
+    let dot = dasm.export_call_graph(GraphFormat::Dot);
+    assert!(dot.starts_with("digraph \"call graph\" {\n"));
+    assert!(dot.contains("    n_C006 [label=\"SUBRT00000000\"];\n"));
+    assert!(dot.contains("    n_C000 -> n_C006 [label=\"call\", style=dashed];\n"));
+    assert!(dot.contains("    n_C000 -> n_FFD2 [label=\"jump\"];\n"));
+    assert!(dot.contains("[label=\"CHROUT\"]"));
+
+    let mermaid = dasm.export_call_graph(GraphFormat::Mermaid);
+    assert!(mermaid.starts_with("flowchart TD\n"));
+    assert!(mermaid.contains("    n_C000 -.->|call| n_C006\n"));
+
+    let cfg = dasm.export_function_cfg(0xC006, GraphFormat::Dot).unwrap();
+    assert!(cfg.contains("n_C006 [label=\"SUBRT00000000\\n$C006-$C006\"]"));
+    assert!(cfg.contains("n_C006 -> n_C009 [label=\"taken\"]"));
+    assert!(cfg.contains("n_C006 -> n_C008 [label=\"fall-through\"]"));
+    assert!(!cfg.contains("return"));
+
+    let cfg = dasm.export_function_cfg(0xC006, GraphFormat::Mermaid).unwrap();
+    assert!(cfg.contains("n_C006[\"SUBRT00000000<br/>$C006-$C006\"]"));
+    assert!(dasm.export_function_cfg(0xC008, GraphFormat::Dot).is_none());
+}
+
+#[test]
+fn rts_dispatch_through_split_tables() {
+    let bytes = [
+        0xA2, 0x00,             // C000 LDX #$00
+        0xBD, 0x10, 0xC0,       // C002 LDA $C010,X
+        0x48,                   // C005 PHA
+        0xBD, 0x0E, 0xC0,       // C006 LDA $C00E,X
+        0x48,                   // C009 PHA
+        0x60,                   // C00A RTS
+        0xEA, 0xEA, 0xEA,       // C00B
+        0x13, 0x15,             // C00E lo table, target-1
+        0xC0, 0xC0,             // C010 hi table
+        0xEA, 0xEA,             // C012
+        0xE8, 0x60,             // C014 INX / RTS
+        0xC8, 0x60,             // C016 INY / RTS
+    ];
+    let mut dasm = dasm_with(&bytes);
+    dasm.disassemble();
+
+    assert_eq!(dasm.get_regions()[0].region_type, RegionType::LoPointers { hi_table: 0xC010, rts: true });
+    assert_eq!((dasm.get_regions()[0].start, dasm.get_regions()[0].end), (0xC00E, 0xC00F));
+
+    // Le tabelle trovate dall'analisi non finiscono nel progetto.
+    let project = dasm.to_project("prg.bin");
+    assert!(project.entry_points.is_empty() && project.regions.is_empty());
+
+    let ops = operands(&dasm);
+    assert_eq!(ops[9], (".byte".to_string(), "<(JUMP_00000000-1)".to_string()));
+    assert_eq!(ops[12], (".byte".to_string(), ">(JUMP_00000001-1)".to_string()));
+    assert_eq!(dasm.get_label(0xC016).unwrap(), "JUMP_00000001");
+    assert!(dasm.get_cfg().function(0xC014).is_some());
+}
+
+#[test]
+fn indirect_jump_dispatch_through_word_table() {
+    let bytes = [
+        0xB9, 0x0F, 0xC0,       // C000 LDA $C00F,Y
+        0x85, 0xFB,             // C003 STA $FB
+        0xB9, 0x10, 0xC0,       // C005 LDA $C010,Y
+        0x85, 0xFC,             // C008 STA $FC
+        0x6C, 0xFB, 0x00,       // C00A JMP ($00FB)
+        0xEA, 0xEA,             // C00D
+        0x13, 0xC0, 0x15, 0xC0, // C00F .word $C013,$C015
+        0xE8, 0x60,             // C013 INX / RTS
+        0xC8, 0x60,             // C015 INY / RTS
+    ];
+    let mut dasm = dasm_with(&bytes);
+    dasm.disassemble();
+
+    let region = dasm.get_regions()[0];
+    assert_eq!((region.start, region.end, region.region_type), (0xC00F, 0xC012, RegionType::Pointers));
+
+    // Le tabelle trovate dall'analisi non finiscono nel progetto.
+    let project = dasm.to_project("prg.bin");
+    assert!(project.entry_points.is_empty() && project.regions.is_empty());
+
+    let ops = operands(&dasm);
+    assert_eq!(ops[7], (".word".to_string(), "JUMP_00000000".to_string()));
+    assert_eq!(ops[8], (".word".to_string(), "JUMP_00000001".to_string()));
+    assert_eq!(ops[9], ("INX".to_string(), "".to_string()));
+    assert!(dasm.get_cfg().function(0xC013).is_some());
+    assert!(dasm.get_cfg().function(0xC015).is_some());
+}
+
+#[test]
+fn trace_coverage_classifies_code_and_data() {
+    let bytes = [
+        0xAD, 0x0A, 0xC0,       // C000 LDA $C00A
+        0x4C, 0x07, 0xC0,       // C003 JMP $C007
+        0xA9,                   // C006 mai eseguito, coprirebbe C007
+        0xE8,                   // C007 INX
+        0x60,                   // C008 RTS
+        0xEA,                   // C009 NOP
+        0xA2,                   // C00A dati letti
+    ];
+    let mut dasm = dasm_with(&bytes);
+    let mut emulator = Emulator6510::new(&BinaryBuffer::new(bytes.to_vec(), LOAD_ADDRESS));
+    emulator.run(LOAD_ADDRESS, 1000, &[]);
+    dasm.apply_trace(emulator.get_trace());
+    dasm.disassemble();
+
+    let region = dasm.get_regions()[0];
+    assert_eq!((region.start, region.end, region.region_type), (0xC00A, 0xC00A, RegionType::Bytes));
+    let expected: Vec<(String, String)> = [
+        ("LDA", "DATA_00000000"),
+        ("JMP", "JUMP_00000000"),
+        (".byte", "$A9"),
+        ("INX", ""),
+        ("RTS", ""),
+        ("NOP", ""),
+        (".byte", "$A2"),
+    ].iter().map(|(o, p)| (o.to_string(), p.to_string())).collect();
+    assert_eq!(operands(&dasm), expected);
+
+    dasm.disassemble();
+    assert_eq!(dasm.get_regions().len(), 1);
+    assert!(dasm.to_project("prg.bin").regions.is_empty());
+}
+
+#[test]
+fn stores_into_instructions_are_self_modifying() {
+    let bytes = [
+        0xA9, 0x05,             // C000 LDA #$05
+        0x8D, 0x09, 0xC0,       // C002 STA $C009
+        0xEE, 0x09, 0xC0,       // C005 INC $C009
+        0xA9, 0x00,             // C008 LDA #$00
+        0x91, 0xC0,             // C00A STA ($C0),Y
+        0x60,                   // C00C RTS
+    ];
+    let mut dasm = dasm_with(&bytes);
+    dasm.disassemble();
+
+    let lines = dasm.get_lines();
+    assert_eq!(lines[1].operand, "PATCH00000000+1");
+    assert_eq!(lines[2].operand, "PATCH00000000+1");
+    assert_eq!(dasm.get_label(0xC008).unwrap(), "PATCH00000000");
+    assert!(lines[3].flags & SELF_MODIFIED_FLAG != 0);
+    assert_eq!(lines[3].comment, "dynamic operand, patched by $C002, $C005");
+    assert_eq!(lines[4].operand, "(zp_ptr_C0),Y");
+    assert!(lines[4].flags & SELF_MODIFIED_FLAG == 0);
+}
+
+#[test]
+fn operands_inside_the_image_become_labels() {
+    let bytes = [
+        0xBD, 0x09, 0xC0,       // C000 LDA $C009,X
+        0xD0, 0x01,             // C003 BNE $C006, dentro il BIT
+        0x2C, 0xA9, 0x00,       // C005 BIT $00A9
+        0x01, 0x02, 0x03, 0x04, // C008 dati
+        0xAD, 0x00, 0xD0,       // C00C LDA $D000, fuori dall'immagine
+        0x60,                   // C00F RTS
+    ];
+    let mut dasm = dasm_with(&bytes);
+    dasm.add_data_region(DataRegion::new(0xC008, 0xC00B, RegionType::Bytes));
+    dasm.disassemble();
+
+    let ops = operands(&dasm);
+    assert_eq!(ops[0].1, "DATA_00000000+1,X");
+    assert_eq!(ops[1].1, "LABEL00000000+1");
+    assert_eq!(ops[4].1, "$D000");
+    assert_eq!(dasm.get_label(0xC005).unwrap(), "LABEL00000000");
+    assert_eq!(dasm.get_label(0xC008).unwrap(), "DATA_00000000");
+    assert!(dasm.get_label(0xC006).is_none());
+    assert!(dasm.get_label(0xC009).is_none());
+}
+
+#[test]
+fn immediate_pairs_become_lo_hi_operators() {
+    let bytes = [
+        0xA9, 0x10,             // C000 LDA #$10
+        0x85, 0xFB,             // C002 STA $FB
+        0xA2, 0xC0,             // C004 LDX #$C0
+        0x86, 0xFC,             // C006 STX $FC
+        0xA9, 0x12,             // C008 LDA #$12
+        0xA0, 0xC0,             // C00A LDY #$C0
+        0x84, 0xFE,             // C00C STY $FE
+        0x85, 0xFD,             // C00E STA $FD
+        0x60,                   // C010 RTS
+        0x01, 0x02,             // C011 dati
+    ];
+    let mut dasm = dasm_with(&bytes);
+    dasm.add_data_region(DataRegion::new(0xC011, 0xC012, RegionType::Bytes));
+    dasm.disassemble();
+
+    let ops = operands(&dasm);
+    assert_eq!(ops[0].1, "#<PNTR_00000000");
+    assert_eq!(ops[2].1, "#>PNTR_00000000");
+    assert_eq!(ops[4].1, "#<(PNTR_00000001+1)");
+    assert_eq!(ops[5].1, "#>(PNTR_00000001+1)");
+    assert_eq!(dasm.get_label(0xC010).unwrap(), "PNTR_00000000");
+    assert_eq!(dasm.get_label(0xC011).unwrap(), "PNTR_00000001");
+}
+
+#[test]
+fn immediate_pairs_ignore_absolute_stores() {
+    let mut dasm = dasm_with(&[
+        0xA9, 0x06, 0x8D, 0x20, 0xD0,   // C000 LDA #$06 / STA $D020
+        0xA9, 0xC0, 0x8D, 0x21, 0xD0,   // C005 LDA #$C0 / STA $D021
+        0x60,
+    ]);
+    dasm.set_platform(Platform::C64);
+    dasm.disassemble();
+
+    let ops = operands(&dasm);
+    assert_eq!(ops[0].1, "#$06");
+    assert_eq!(ops[2].1, "#$C0");
+    assert!(dasm.get_label(0xC005).is_none());
+    let comments: Vec<&str> = dasm.get_lines().iter().map(|l| l.comment.as_str()).collect();
+    assert_eq!(comments[1], "border colour: blue");
+    assert_eq!(comments[3], "background colour 0: black");
+}
+
+#[test]
+fn immediate_pairs_need_a_straight_line() {
+    let bytes = [
+        0xA9, 0x10,             // C000 LDA #$10
+        0x85, 0xFB,             // C002 STA $FB
+        0x20, 0x0B, 0xC0,       // C004 JSR $C00B, può cambiare i registri
+        0xA9, 0xC0,             // C007 LDA #$C0
+        0x85, 0xFC,             // C009 STA $FC
+        0x60,                   // C00B RTS
+    ];
+    let mut dasm = dasm_with(&bytes);
+    dasm.disassemble();
+
+    let ops = operands(&dasm);
+    assert_eq!(ops[0].1, "#$10");
+    assert_eq!(ops[3].1, "#$C0");
+}
+
+#[test]
+fn zero_page_usage_and_names() {
+    let bytes = [
+        0xA9, 0x00,             // C000 LDA #$00
+        0x85, 0xFB,             // C002 STA $FB
+        0xA9, 0x04,             // C004 LDA #$04
+        0x85, 0xFC,             // C006 STA $FC
+        0xA0, 0x00,             // C008 LDY #$00
+        0xB1, 0xFB,             // C00A LDA ($FB),Y
+        0xE6, 0x02,             // C00C INC $02
+        0xA5, 0x2B,             // C00E LDA $2B
+        0x60,                   // C010 RTS
+    ];
+    let mut dasm = dasm_with(&bytes);
+    dasm.set_platform(Platform::C64);
+    dasm.set_label(0x02, "counter");
+    dasm.disassemble();
+
+    let ops = operands(&dasm);
+    assert_eq!(ops[1].1, "zp_ptr_FB");
+    assert_eq!(ops[3].1, "zp_ptr_FB+1");
+    assert_eq!(ops[5].1, "(zp_ptr_FB),Y");
+    assert_eq!(ops[6].1, "counter");
+    assert_eq!(ops[7].1, "TXTTAB");
+
+    let usage = dasm.get_zero_page_usage();
+    assert_eq!(usage.len(), 4);
+    assert_eq!(usage[2], ZeroPageUsage { address: 0xFB, name: "zp_ptr_FB".to_string(), reads: 1, writes: 1, pointer: true });
+    assert_eq!(usage[3].name, "zp_ptr_FB+1");
+    assert_eq!(dasm.get_label(0xFB).unwrap(), "zp_ptr_FB");
+    assert!(dasm.get_label(0xFC).is_none());
+
+    assert_eq!(dasm.zero_page_equates(), vec![
+        "counter         = $02    ; reads 1, writes 1",
+        "TXTTAB          = $2B    ; reads 1, writes 0",
+        "zp_ptr_FB       = $FB    ; reads 1, writes 1, pointer",
+    ]);
+}
+
+#[test]
+fn detected_strings_become_text_regions() {
+    let mut bytes = vec![0x60];                                 // C000 RTS
+    bytes.extend_from_slice(b"HELLO\x00");                     // C001
+    bytes.extend_from_slice(&[b'E', b'N', b'D' | 0x80, b'F', b'O', b'R' | 0x80]); // C007
+    let mut dasm = dasm_with(&bytes);
+
+    dasm.set_string_detection(Some(4));
+    dasm.disassemble();
+
+    let regions = dasm.get_regions();
+    assert_eq!(regions.len(), 2);
+    assert_eq!((regions[0].start, regions[0].end, regions[0].region_type), (0xC001, 0xC006, RegionType::NullTerminated));
+    assert_eq!((regions[1].start, regions[1].end, regions[1].region_type), (0xC007, 0xC00C, RegionType::Shifted));
+    let ops = operands(&dasm);
+    assert_eq!(ops[1], (".null".to_string(), "\"HELLO\"".to_string()));
+    assert_eq!(ops[2], (".shift".to_string(), "\"END\"".to_string()));
+    assert_eq!(ops[3], (".shift".to_string(), "\"FOR\"".to_string()));
+}
+
+#[test]
+fn long_terminated_strings_end_with_the_terminating_directive() {
+    let mut bytes = vec![0x60];
+    bytes.extend_from_slice(b"ABCDEFGHIJKLMNOPQRS\x00");   // C001, 19 caratteri
+    let mut dasm = dasm_with(&bytes);
+    dasm.add_data_region(DataRegion::new(0xC001, 0xC014, RegionType::NullTerminated));
+    dasm.disassemble();
+
+    let ops = operands(&dasm);
+    assert_eq!(ops[1], (".text".to_string(), "\"ABCDEFGHIJKLMNOP\"".to_string()));
+    assert_eq!(ops[2], (".null".to_string(), "\"QRS\"".to_string()));
+}
+
+#[test]
+fn unreached_gaps_are_classified() {
+    let mut bytes = vec![0x60];                                 // C000 RTS
+    bytes.extend_from_slice(&[0xA9, 0x01, 0x8D, 0x20, 0xD0, 0x60]); // C001 codice mai chiamato
+    bytes.extend_from_slice(b"PRESS PLAY ON TAPE");            // C007
+    bytes.extend_from_slice(&[0xEA, 0xEA, 0xEA]);
+    let mut dasm = dasm_with(&bytes);
+    dasm.disassemble();
+
+    let gaps = dasm.classify_gaps();
+    assert_eq!(gaps.len(), 2);
+    assert_eq!((gaps[0].start, gaps[0].end, gaps[0].kind, gaps[0].confidence), (0xC001, 0xC006, GapKind::Code, Confidence::High));
+    assert!(gaps[0].evidence.terminated);
+    assert_eq!(gaps[1].start, 0xC007);
+    assert_eq!((gaps[1].kind, gaps[1].confidence), (GapKind::Data, Confidence::High));
+    assert!(gaps[1].evidence.text_ratio > 0.8);
+    assert!(!gaps[1].needs_review());
+
+    assert_eq!(dasm.apply_classification(&gaps, Confidence::High), 2);
+    dasm.disassemble();
+    assert!(dasm.get_cfg().function(0xC001).is_some());
+    assert!(dasm.classify_gaps().is_empty());
+
+    // Le classificazioni si riapplicano senza duplicarsi e non finiscono
+    // nel progetto.
+    dasm.disassemble();
+    assert_eq!(dasm.get_regions().len(), 1);
+    let project = dasm.to_project("prg.bin");
+    assert!(project.entry_points.is_empty() && project.regions.is_empty());
+}
+
+#[test]
+fn cycles_and_page_crossing_branches() {
+    let mut bytes = vec![
+        0xA2, 0x05,                 // C000 LDX #$05
+        0xBD, 0x00, 0xC1,           // C002 LDA $C100,X
+        0xCA,                       // C005 DEX
+        0xD0, 0xFA,                 // C006 BNE $C002
+    ];
+    bytes.resize(0xFE, 0xEA);
+    bytes.extend_from_slice(&[0xD0, 0xF0, 0x60]);              // C0FE BNE $C0F0, C100 RTS
+    let mut dasm = dasm_with(&bytes);
+    dasm.disassemble();
+
+    let cycles: Vec<String> = dasm.get_lines()[..4].iter().map(|l| l.cycles_text()).collect();
+    assert_eq!(cycles, ["2", "4+", "2", "2/3"]);
+
+    let block = dasm.get_cfg().block_at(0xC002).unwrap();
+    assert_eq!(dasm.block_cycles(block), 8);
+
+    let crossing = dasm.get_lines().iter().find(|l| l.address == 0xC0FE).unwrap();
+    assert_eq!(crossing.cycles_text(), "2/4");
+    assert!(crossing.comment.contains("WARNING: branch crosses a page"));
+    assert_eq!(dasm.get_warnings(), ["$C0FE: branch crosses a page, +1 cycle when taken"]);
+}
+
+#[test]
+fn cycle_totals_skip_lone_unreached_instructions() {
+    let mut dasm = dasm_with(&[
+        0x60,                       // C000 RTS
+        0x60,                       // C001 RTS, mai raggiunto
+        0xE8, 0xC8, 0x60,           // C002 INX / INY / RTS, mai raggiunti
+    ]);
+    dasm.disassemble();
+
+    assert_eq!(dasm.block_total_at(0), Some(6));
+    assert_eq!(dasm.block_total_at(1), None);
+    assert_eq!(dasm.block_total_at(4), Some(10));
+}
+
+#[test]
+fn io_accesses_are_annotated() {
+    let mut dasm = dasm_with(&[
+        0xA9, 0x1B, 0x8D, 0x11, 0xD0,   // C000 LDA #$1B / STA $D011
+        0xA2, 0x0E, 0x8E, 0x20, 0xD0,   // C005 LDX #$0E / STX $D020
+        0xAD, 0x12, 0xD0,               // C00A LDA $D012
+        0x9D, 0x00, 0xD0,               // C00D STA $D000,X
+        0x8D, 0x0D, 0xDC,               // C010 STA $DC0D
+        0x8D, 0x00, 0xDE,               // C013 STA $DE00
+        0x60,
+    ]);
+    dasm.set_platform(Platform::C64);
+    static CARTRIDGE: &[IoRegister] = &[IoRegister { address: 0xDE00, read: "bank", write: "select bank", fields: &[BitField::Value { mask: 0x3F, name: "BANK" }] }];
+    dasm.add_io_registers(CARTRIDGE);
+    dasm.disassemble();
+
+    let comments: Vec<&str> = dasm.get_lines().iter().map(|l| l.comment.as_str()).collect();
+    assert_eq!(comments[1], "VIC control 1: 25 rows, screen on, YSCROLL=3");
+    assert_eq!(comments[3], "border colour: light blue");
+    assert_eq!(comments[4], "current raster line");
+    assert_eq!(comments[5], "sprite 0 X");
+    assert_eq!(comments[6], "CIA1 interrupt mask");
+    assert_eq!(comments[7], "select bank");
+}
+
+#[test]
+fn entry_points_realign_the_sweep() {
+    let memory = BinaryBuffer::new(vec![0x2C, 0xA9, 0x00, 0x60], LOAD_ADDRESS);  // BIT $00A9 / LDA #$00
+    let mut dasm = Dasm::with_entry_points(Box::new(Cpu6510::new(memory)), &[0xC001, 0xC000, 0xD000]);
+    assert_eq!(dasm.get_entry_points(), [0xC000, 0xC001]);
+    dasm.disassemble();
+
+    let ops = operands(&dasm);
+    assert_eq!(ops[0], (".byte".to_string(), "$2C".to_string()));
+    assert_eq!(ops[1], ("LDA".to_string(), "#$00".to_string()));
+    assert!(dasm.get_label(0xC000).is_some() && dasm.get_label(0xC001).is_some());
+}
+
+#[test]
+fn hardware_vectors_seed_entry_points() {
+    let mut bytes = vec![
+        0x60,                       // FFF0 NMI: RTS
+        0xA9, 0x00, 0x40,           // FFF1 RESET: LDA #$00 / RTI
+        0x60,                       // FFF4 IRQ: RTS
+        0xEA, 0xEA, 0xEA, 0xEA, 0xEA,
+    ];
+    bytes.extend_from_slice(&[0xF0, 0xFF, 0xF1, 0xFF, 0xF4, 0xFF]);
+    let memory = BinaryBuffer::new(bytes, 0xFFF0);
+    let mut dasm = Dasm::new(Box::new(Cpu6510::new(memory)), 5);
+
+    dasm.set_vector_seeding(true);
+    dasm.disassemble();
+    assert_eq!(dasm.get_entry_points(), [0xFFF0, 0xFFF1, 0xFFF4]);
+    assert!(dasm.to_project("prg.bin").entry_points.is_empty());
+
+    let lines = dasm.get_lines();
+    assert_eq!(lines[0].address, 0xFFF0);
+    let reset = dasm.get_label(0xFFF1).unwrap().clone();
+    let vector = lines.iter().find(|l| l.address == 0xFFFC).unwrap();
+    assert_eq!((vector.opcode.as_str(), &vector.operand), (".word", &reset));
+    assert!(dasm.get_cfg().function(0xFFF4).is_some());
+}
+
+#[test]
+fn entry_points_follow_jumps_over_data() {
+    let mut bytes = vec![0x4C, 0x10, 0xC0];                                 // C000 JMP $C010
+    bytes.extend_from_slice(&[0xA5, 0xFB, 0x85, 0xFC, 0xB1, 0xFB, 0x91, 0xFD]); // C003 dati
+    bytes.extend_from_slice(&[0x00, 0x07, 0x0E, 0x15, 0x20]);                 // C00B dati, l'ultimo coprirebbe C010
+    bytes.extend_from_slice(&[0xA9, 0x01, 0x60]);                           // C010 LDA #$01 / RTS
+    let memory = BinaryBuffer::new(bytes, LOAD_ADDRESS);
+    let mut dasm = Dasm::with_entry_points(Box::new(Cpu6510::new(memory)), &[0xC000]);
+    dasm.disassemble();
+
+    let lines = dasm.get_lines();
+    let target = lines.iter().find(|l| l.address == 0xC010).unwrap();
+    assert_eq!((target.opcode.as_str(), target.operand.as_str()), ("LDA", "#$01"));
+    assert!(lines.iter().filter(|l| (0xC003..0xC010).contains(&l.address)).all(|l| l.line_type == LineType::Data));
+    assert!(dasm.zero_page_equates().is_empty());
+
+    // I byte non raggiunti restano una zona da classificare.
+    let gaps = dasm.classify_gaps();
+    assert_eq!(gaps.first().map(|g| g.start), Some(0xC003));
+    assert!(gaps.iter().all(|g| g.end < 0xC010));
+}
+
+#[test]
+fn installed_interrupt_handlers_become_entry_points() {
+    let bytes = [
+        0x78,                                               // C000 SEI
+        0xA9, 0x17, 0x8D, 0x14, 0x03, 0xA9, 0xC0, 0x8D, 0x15, 0x03, // C001 IRQ -> $C017
+        0xA9, 0x1D, 0x8D, 0x18, 0x03, 0xA9, 0xC0, 0x8D, 0x19, 0x03, // C00B NMI -> $C01D
+        0x58, 0x60,                                         // C015 CLI / RTS
+        0xEE, 0x19, 0xD0, 0x4C, 0x31, 0xEA,                 // C017 INC $D019 / JMP $EA31
+        0x40,                                               // C01D RTI
+    ];
+    let mut dasm = dasm_with(&bytes);
+    dasm.set_platform(Platform::C64);
+    dasm.disassemble();
+
+    let handlers = dasm.get_interrupt_handlers();
+    assert_eq!(handlers.len(), 2);
+    assert_eq!((handlers[0].vector, handlers[0].address, handlers[0].exits.as_slice()), (0x0314, 0xC017, [0xC01A].as_slice()));
+    assert_eq!((handlers[1].vector, handlers[1].address, handlers[1].exits.as_slice()), (0x0318, 0xC01D, [0xC01D].as_slice()));
+    assert_eq!(dasm.get_label(0xC017).unwrap(), "irq_handler_0");
+    assert_eq!(dasm.get_label(0xC01D).unwrap(), "irq_handler_1");
+    assert!(dasm.get_entry_points().contains(&0xC017));
+
+    let ops = operands(&dasm);
+    assert_eq!(ops[1].1, "#<irq_handler_0");
+    assert_eq!(ops[3].1, "#>irq_handler_0");
+    let exit = dasm.get_lines().iter().find(|l| l.address == 0xC01A).unwrap();
+    assert!(exit.comment.ends_with("end of irq_handler_0"));
+
+    // Seguendo il flusso i gestori sono raggiunti solo tramite il vettore.
+    let memory = BinaryBuffer::new(bytes.to_vec(), LOAD_ADDRESS);
+    let mut dasm = Dasm::with_entry_points(Box::new(Cpu6510::new(memory)), &[LOAD_ADDRESS]);
+    dasm.set_platform(Platform::C64);
+    dasm.disassemble();
+    let opcode_at = |address: u32| dasm.get_lines().iter().find(|l| l.address == address).map(|l| l.opcode.clone());
+    assert_eq!(opcode_at(0xC017).as_deref(), Some("INC"));
+    assert_eq!(opcode_at(0xC01D).as_deref(), Some("RTI"));
+}
+
+#[test]
+fn json_exports() {
+    let mut dasm = dasm_with(&[0xA9, 0x00, 0xF0, 0x01, 0x60, 0x4C, 0x00, 0xC0, 0x12, 0x34]);
+    dasm.add_data_region(DataRegion::new(0xC008, 0xC009, RegionType::Bytes));
+    dasm.disassemble();
+
+    let json: serde_json::Value = serde_json::from_str(&dasm.export_json()).unwrap();
+    assert_eq!(json["version"], 1);
+    assert_eq!(json["load_address"], "$C000");
+    assert_eq!(json["regions"][0]["type"], "bytes");
+    let lines = json["lines"].as_array().unwrap();
+    assert_eq!(lines.len(), dasm.get_lines().len());
+    assert_eq!(lines[0]["bytes"], serde_json::json!([0xA9, 0x00]));
+    assert_eq!(lines[0]["type"], "instruction");
+    assert_eq!(lines[1]["mnemonic"], "BEQ");
+    assert_eq!(lines[1]["address_ref"], "$C005");
+    assert_eq!(lines[1]["flags"], serde_json::json!(["valid", "branch"]));
+    assert_eq!(lines[3]["label"], json["labels"][1]["name"]);
+    assert_eq!(lines[4]["type"], "data");
+
+    let records: Vec<serde_json::Value> = dasm.export_jsonl().lines().map(|l| serde_json::from_str(l).unwrap()).collect();
+    assert_eq!(records[0]["record"], "header");
+    assert_eq!(records[0]["version"], 1);
+    let count = |kind: &str| records.iter().filter(|r| r["record"] == kind).count();
+    assert_eq!((count("label"), count("region"), count("line")), (2, 1, lines.len()));
+}
+
+#[test]
+fn html_listing_links_labels_and_callers() {
+    let mut bytes = vec![
+        0x20, 0x0C, 0xC0,           // C000 JSR $C00C
+        0xA9, 0x0D, 0x85, 0xFB,     // C003 LDA #<$C00D / STA $FB
+        0xA9, 0xC0, 0x85, 0xFC,     // C007 LDA #>$C00D / STA $FC
+        0x60,                       // C00B RTS
+        0x60,                       // C00C RTS
+    ];
+    bytes.extend_from_slice(b"HELLO WORLD");
+    let mut dasm = dasm_with(&bytes);
+    dasm.add_data_region(DataRegion::new(0xC00D, 0xC017, RegionType::Petscii));
+    dasm.disassemble();
+
+    let html = dasm.export_html("test <rom>");
+    assert!(html.contains("<title>test &lt;rom&gt;</title>"));
+    let subroutine = dasm.get_label(0xC00C).unwrap();
+    assert!(html.contains(&format!("<div class=\"label\" id=\"L_C00C\">{}:</div>", subroutine)));
+    assert!(html.contains(&format!("<a href=\"#L_C00C\">{}</a>", subroutine)));
+    assert!(html.contains("<a href=\"#A_C000\">$C000</a> call"));
+    assert!(html.contains("#&lt;<a href="));
+    assert!(html.contains("class=\"line text\""));
+    assert_eq!(html.matches("<details open>").count(), html.matches("</details>").count());
+    assert_eq!(html.matches("<details open>").count(), 2);
+}
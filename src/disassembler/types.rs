@@ -1,27 +1,84 @@
-use super::UNINITIALIZED_OPCODE;
+use serde::Serialize;
 
-#[derive(Debug, Default)]
+use super::{BRANCH_OPCODE, JAM_OPCODE, LOAD_FLAG, STORE_FLAG, UNDOC_OPCODE, UNINITIALIZED_OPCODE, UNSTABLE_OPCODE};
+
+#[derive(Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum LineType {
     #[default] None,
     Instruction,
     UnknownInstruction,
+    Data,
     Comment,
 }
 
+// Stabilità di un opcode non documentato sul silicio NMOS.
+#[derive(Debug, PartialEq)]
+pub enum OpcodeStability {
+    Stable,
+    Unstable,
+    Jam,
+}
+
+impl OpcodeStability {
+    pub fn from_flags(flags: u32) -> Self {
+        if flags & JAM_OPCODE != 0 {
+            OpcodeStability::Jam
+        }else if flags & UNSTABLE_OPCODE != 0 {
+            OpcodeStability::Unstable
+        }else{
+            OpcodeStability::Stable
+        }
+    }
+}
+
+// Come l'istruzione accede al suo indirizzo effettivo.
+#[derive(Debug, PartialEq)]
+pub enum MemoryAccess {
+    None,
+    Read,
+    Write,
+    ReadModifyWrite,
+}
+
+impl MemoryAccess {
+    pub fn from_flags(flags: u32) -> Self {
+        match (flags & LOAD_FLAG != 0, flags & STORE_FLAG != 0) {
+            (true, true) => MemoryAccess::ReadModifyWrite,
+            (false, true) => MemoryAccess::Write,
+            (true, false) => MemoryAccess::Read,
+            (false, false) => MemoryAccess::None,
+        }
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct DisassembledLine {
     pub line_type: LineType,
     pub instr_size: u8,
     pub address: u32,
     pub address_ref: u32,
-    offset: u32,
-    return_address: u32,
+    pub offset: u32,
+    pub return_address: u32,
     pub byte_code: [u8;16],
     pub opcode: String,
     pub operand: String,
-    label: String,
+    // Parte dell'operando che rappresenta address_ref, vuota se l'operando
+    // non fa riferimento a un indirizzo.
+    pub address_text: String,
+    pub label: String,
     pub comment: String,
-    pub flags: u32
+    pub flags: u32,
+    // Maschere dei registri letti e scritti, il significato dei bit
+    // dipende dalla CPU.
+    pub regs_read: u32,
+    pub regs_written: u32,
+    // Numero di ripetizioni di byte_code, usato dalle righe .fill.
+    pub repeat_count: u32,
+    // Cicli base e massimo di cicli aggiuntivi: per i branch quelli del
+    // salto preso, per gli altri l'attraversamento di pagina.
+    pub cycles: u32,
+    pub extra_cycles: u32
 }
 
 impl DisassembledLine {
@@ -35,10 +92,50 @@ impl DisassembledLine {
             return_address: 0,
             operand: String::from(""),
             opcode: String::from(""),
+            address_text: String::from(""),
             byte_code: [0; 16],
             label: String::from(""),
             comment: String::from(""),
-            flags: UNINITIALIZED_OPCODE
+            flags: UNINITIALIZED_OPCODE,
+            regs_read: 0,
+            regs_written: 0,
+            repeat_count: 1,
+            cycles: 0,
+            extra_cycles: 0
         }
     }
+
+    // Numero di byte coperti dalla riga.
+    pub fn size(&self) -> u32 {
+        (self.instr_size as u32 + 1) * self.repeat_count
+    }
+
+    // "4", "4+" se può attraversare una pagina, "2/3" per i branch.
+    pub fn cycles_text(&self) -> String {
+        if self.line_type != LineType::Instruction {
+            String::new()
+        }else if self.flags & BRANCH_OPCODE != 0 {
+            format!("{}/{}", self.cycles, self.cycles + self.extra_cycles)
+        }else if self.extra_cycles > 0 {
+            format!("{}+", self.cycles)
+        }else{
+            self.cycles.to_string()
+        }
+    }
+
+    pub fn has_reference(&self) -> bool {
+        !self.address_text.is_empty()
+    }
+
+    pub fn memory_access(&self) -> MemoryAccess {
+        MemoryAccess::from_flags(self.flags)
+    }
+
+    pub fn is_undocumented(&self) -> bool {
+        self.flags & UNDOC_OPCODE != 0
+    }
+
+    pub fn stability(&self) -> OpcodeStability {
+        OpcodeStability::from_flags(self.flags)
+    }
 }
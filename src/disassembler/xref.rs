@@ -0,0 +1,106 @@
+use std::{collections::BTreeMap, fmt};
+
+use super::{types::{DisassembledLine, LineType, MemoryAccess}, Dasm, BRANCH_OPCODE, INDIRECT_FLAG, JMP_OPCODE, SUBROUTINE_OPCODE};
+
+const XREFS_PER_LINE: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum XrefKind {
+    Call,
+    Jump,
+    Branch,
+    Read,
+    Write,
+    ReadModifyWrite,
+    // Puntatore in una tabella dati.
+    Pointer,
+    // Accesso di un opcode senza LOAD_FLAG/STORE_FLAG.
+    Access,
+}
+
+impl XrefKind {
+    pub fn from_line(line: &DisassembledLine) -> Self {
+        if line.line_type == LineType::Data {
+            return XrefKind::Pointer;
+        }
+        if line.flags & SUBROUTINE_OPCODE != 0 {
+            return XrefKind::Call;
+        }
+        if line.flags & BRANCH_OPCODE != 0 {
+            return XrefKind::Branch;
+        }
+        if line.flags & JMP_OPCODE != 0 && line.flags & INDIRECT_FLAG == 0 {
+            return XrefKind::Jump;
+        }
+
+        match line.memory_access() {
+            MemoryAccess::ReadModifyWrite => XrefKind::ReadModifyWrite,
+            MemoryAccess::Write => XrefKind::Write,
+            MemoryAccess::Read => XrefKind::Read,
+            MemoryAccess::None => XrefKind::Access,
+        }
+    }
+}
+
+impl fmt::Display for XrefKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            XrefKind::Call => "call",
+            XrefKind::Jump => "jump",
+            XrefKind::Branch => "branch",
+            XrefKind::Read => "read",
+            XrefKind::Write => "write",
+            XrefKind::ReadModifyWrite => "rmw",
+            XrefKind::Pointer => "pointer",
+            XrefKind::Access => "access",
+        };
+        f.write_str(name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Xref {
+    pub from: u32,
+    pub kind: XrefKind
+}
+
+impl Dasm {
+    pub(super) fn build_xrefs(&mut self) {
+        self.xrefs.clear();
+
+        for line in self.lines.iter().filter(|l| l.has_reference()) {
+            self.xrefs.entry(line.address_ref).or_default().push(Xref {
+                from: line.address,
+                kind: XrefKind::from_line(line)
+            });
+        }
+    }
+
+    // Riferimenti verso l'indirizzo, nell'ordine in cui compaiono.
+    pub fn get_xrefs(&self, address: u32) -> &[Xref] {
+        self.xrefs.get(&address).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn get_xrefs_of_kind(&self, address: u32, kind: XrefKind) -> Vec<u32> {
+        self.get_xrefs(address).iter().filter(|x| x.kind == kind).map(|x| x.from).collect()
+    }
+
+    pub fn get_callers(&self, address: u32) -> Vec<u32> {
+        self.get_xrefs_of_kind(address, XrefKind::Call)
+    }
+
+    pub fn get_all_xrefs(&self) -> &BTreeMap<u32, Vec<Xref>> {
+        &self.xrefs
+    }
+
+    // Righe di commento da stampare sotto la label.
+    pub(super) fn xref_comments(&self, address: u32) -> Vec<String> {
+        self.get_xrefs(address)
+            .chunks(XREFS_PER_LINE)
+            .map(|chunk| {
+                let refs: Vec<String> = chunk.iter().map(|x| format!("${:04X} {}", x.from, x.kind)).collect();
+                format!("; xref: {}", refs.join(", "))
+            })
+            .collect()
+    }
+}
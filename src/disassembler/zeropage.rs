@@ -0,0 +1,116 @@
+use std::collections::BTreeMap;
+
+use super::{types::{DisassembledLine, LineType, MemoryAccess}, Dasm, BRANCH_OPCODE};
+
+// Uso di una locazione di pagina zero nel programma.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZeroPageUsage {
+    pub address: u32,
+    pub name: String,
+    pub reads: u32,
+    pub writes: u32,
+    // Usata come puntatore con (zp),Y o (zp,X).
+    pub pointer: bool
+}
+
+impl ZeroPageUsage {
+    fn new(address: u32) -> Self {
+        ZeroPageUsage {
+            address,
+            name: String::new(),
+            reads: 0,
+            writes: 0,
+            pointer: false
+        }
+    }
+
+    // Il byte alto di un puntatore è espresso come nome+1 e non ha equate.
+    pub fn has_equate(&self) -> bool {
+        !self.name.contains('+')
+    }
+}
+
+fn is_zero_page(line: &DisassembledLine) -> bool {
+    line.line_type == LineType::Instruction && line.instr_size == 1 && line.has_reference() && line.flags & BRANCH_OPCODE == 0
+}
+
+fn is_indirect(line: &DisassembledLine) -> bool {
+    line.operand.starts_with('(')
+}
+
+impl Dasm {
+    // Raccoglie le locazioni di pagina zero usate, dà un nome a quelle
+    // senza label e lo sostituisce negli operandi. Le label dell'utente,
+    // anche dal progetto, e i simboli della piattaforma hanno la precedenza.
+    pub(super) fn analyze_zero_page(&mut self) {
+        let mut usage: BTreeMap<u32, ZeroPageUsage> = BTreeMap::new();
+
+        for line in self.lines.iter().filter(|l| is_zero_page(l)) {
+            let address = line.address_ref;
+            if is_indirect(line) {
+                let pointer = usage.entry(address).or_insert_with(|| ZeroPageUsage::new(address));
+                pointer.pointer = true;
+                pointer.reads += 1;
+                let hi = (address + 1) & 0xFF;
+                usage.entry(hi).or_insert_with(|| ZeroPageUsage::new(hi)).reads += 1;
+                continue;
+            }
+
+            let entry = usage.entry(address).or_insert_with(|| ZeroPageUsage::new(address));
+            match line.memory_access() {
+                MemoryAccess::Read => entry.reads += 1,
+                MemoryAccess::Write => entry.writes += 1,
+                MemoryAccess::ReadModifyWrite => {
+                    entry.reads += 1;
+                    entry.writes += 1;
+                },
+                MemoryAccess::None => {}
+            }
+        }
+
+        let addresses: Vec<u32> = usage.keys().copied().collect();
+        for address in addresses {
+            let previous = address.checked_sub(1).and_then(|a| usage.get(&a)).filter(|u| u.pointer && u.has_equate());
+            let name = if let Some(label) = self.labels_map.get(&address) {
+                label.clone()
+            }else if let Some(symbol) = self.platform_symbols.get(&address) {
+                symbol.name.to_owned()
+            }else if usage[&address].pointer {
+                format!("zp_ptr_{:02X}", address)
+            }else if let Some(pointer) = previous {
+                format!("{}+1", pointer.name)
+            }else{
+                format!("zp_{:02X}", address)
+            };
+
+            let entry = usage.get_mut(&address).unwrap();
+            if entry.has_equate() && !name.contains('+') {
+                self.labels_map.entry(address).or_insert_with(|| name.clone());
+            }
+            entry.name = name;
+        }
+
+        for line in self.lines.iter_mut().filter(|l| is_zero_page(l)) {
+            if let Some(entry) = usage.get(&line.address_ref) {
+                line.operand = line.operand.replacen(&line.address_text, &entry.name, 1);
+            }
+        }
+
+        self.zero_page = usage.into_values().collect();
+    }
+
+    pub fn get_zero_page_usage(&self) -> &[ZeroPageUsage] {
+        &self.zero_page
+    }
+
+    // Blocco di equate per l'inizio del sorgente.
+    pub fn zero_page_equates(&self) -> Vec<String> {
+        self.zero_page.iter()
+            .filter(|u| u.has_equate())
+            .map(|u| {
+                let pointer = if u.pointer { ", pointer" } else { "" };
+                format!("{:<16}= ${:02X}    ; reads {}, writes {}{}", u.name, u.address, u.reads, u.writes, pointer)
+            })
+            .collect()
+    }
+}
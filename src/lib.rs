@@ -0,0 +1,5 @@
+pub mod utils;
+pub mod disassembler;
+pub mod cpus;
+pub mod memory;
+pub mod platforms;
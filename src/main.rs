@@ -1,18 +1,119 @@
-use disassembler::Dasm;
-use memory::BinaryBuffer;
-use cpus::{mos6510::Cpu6510, CpuTrait};
+use std::{io, process};
 
-pub mod utils;
-mod disassembler;
-mod cpus;
-mod memory;
+use dasm::disassembler::{project::Project, Dasm};
+use dasm::memory::BinaryBuffer;
+use dasm::cpus::{mos6510::Cpu6510, CpuTrait};
+use dasm::platforms::Platform;
 
+const USAGE: &str = "usage: dasm [project.json] [--format text|json|jsonl|html] [--output file]";
 
-fn main() {
-    let bytes = std::fs::read("./basic-901226-01.bin").unwrap();
-    let memory:BinaryBuffer = BinaryBuffer::new(bytes, 0xA000);
+// Formato del listato in uscita.
+#[derive(Clone, Copy, PartialEq)]
+enum Format {
+    Text,
+    Json,
+    Jsonl,
+    Html,
+}
+
+struct Options {
+    project: Option<String>,
+    format: Format,
+    output: Option<String>
+}
+
+fn parse_args(args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options { project: None, format: Format::Text, output: None };
+    let mut args = args;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => {
+                options.format = match args.next().as_deref() {
+                    Some("text") => Format::Text,
+                    Some("json") => Format::Json,
+                    Some("jsonl") => Format::Jsonl,
+                    Some("html") => Format::Html,
+                    Some(other) => return Err(format!("unknown format '{}'", other)),
+                    None => return Err("--format needs a value".to_owned())
+                };
+            },
+            "--output" => options.output = Some(args.next().ok_or("--output needs a path")?),
+            _ if arg.starts_with("--") => return Err(format!("unknown option '{}'", arg)),
+            _ if options.project.is_none() => options.project = Some(arg),
+            _ => return Err(format!("unexpected argument '{}'", arg))
+        }
+    }
+    if options.format == Format::Text && options.output.is_some() {
+        return Err("--output needs --format json, jsonl or html".to_owned());
+    }
+    Ok(options)
+}
+
+// Il testo va sempre sul terminale, gli altri formati nel file indicato
+// oppure sul terminale.
+fn write_listing(dasm: &mut Dasm, options: &Options) -> io::Result<()> {
+    dasm.disassemble();
+    let listing = match options.format {
+        Format::Text => {
+            dasm.print_dasm();
+            return Ok(());
+        },
+        Format::Json => dasm.export_json(),
+        Format::Jsonl => dasm.export_jsonl(),
+        Format::Html => dasm.export_html(&listing_title(options)),
+    };
+    match &options.output {
+        Some(path) => std::fs::write(path, listing),
+        None => {
+            print!("{}", listing);
+            Ok(())
+        }
+    }
+}
+
+// Il titolo della pagina è il nome del progetto, se c'è.
+fn listing_title(options: &Options) -> String {
+    let path = options.project.as_deref().unwrap_or("basic-901226-01.bin");
+    std::path::Path::new(path).file_stem().map_or(path.to_owned(), |s| s.to_string_lossy().into_owned())
+}
+
+// Riprende il lavoro salvato in un file di progetto.
+fn open_project(path: &str) -> io::Result<Dasm> {
+    let project = Project::load(path)?;
+    let memory = project.load_binary()?;
     let cpu: Box<dyn CpuTrait> = Box::new(Cpu6510::new(memory));
 
-    let mut dasm = Dasm::new(cpu,0x38a);
-    dasm.run();
+    let mut dasm = Dasm::new(cpu,0);
+    dasm.apply_project(&project)?;
+    Ok(dasm)
+}
+
+fn main() {
+    let options = parse_args(std::env::args().skip(1)).unwrap_or_else(|error| {
+        eprintln!("{}\n{}", error, USAGE);
+        process::exit(2);
+    });
+
+    let mut dasm = if let Some(project_path) = &options.project {
+        open_project(project_path).unwrap_or_else(|error| {
+            eprintln!("cannot open {}: {}", project_path, error);
+            process::exit(1);
+        })
+    }else{
+        let bytes = std::fs::read("./basic-901226-01.bin").unwrap();
+        let memory:BinaryBuffer = BinaryBuffer::new(bytes, 0xA000);
+        let cpu: Box<dyn CpuTrait> = Box::new(Cpu6510::new(memory));
+
+        let mut dasm = Dasm::new(cpu,0x38a);
+        dasm.set_platform(Platform::C64);
+        dasm.set_vector_seeding(true);
+        dasm.set_string_detection(Some(8));
+        dasm
+    };
+
+    if let Err(error) = write_listing(&mut dasm, &options) {
+        eprintln!("cannot write the listing: {}", error);
+        process::exit(1);
+    }
 }
@@ -1,5 +1,5 @@
 
-trait BusSize {}
+pub trait BusSize {}
 
 impl BusSize for u16 {}
 impl BusSize for u32 {}
@@ -38,6 +38,18 @@ impl BinaryBuffer {
     pub fn get_loaded_address(&self) -> u32 {
         self.loaded_address
     }
+    pub fn get_data(&self) -> &[u8] {
+        &self.data
+    }
+
+    // Converte un indirizzo assoluto in offset nel buffer, se vi ricade.
+    pub fn to_offset(&self, address: u32) -> Option<u32> {
+        if address >= self.loaded_address && ((address - self.loaded_address) as usize) < self.buffer_size {
+            Some(address - self.loaded_address)
+        }else{
+            None
+        }
+    }
 
     pub fn read_byte(&self, offset: u32) -> u8 {
         self.data[offset as usize]
@@ -52,9 +64,9 @@ impl BinaryBuffer {
         word
     }
 
-    fn read_word_be(&self, offset: u32) -> u16 {
+    pub fn read_word_be(&self, offset: u32) -> u16 {
         let mut word:u16 = self.data[offset as usize] as u16;
-        word|=((self.data[(offset+1) as usize] as u16) << 8) as u16;
+        word|=(self.data[(offset+1) as usize] as u16) << 8;
         word
     }
     // Potrebbe essere un trait il fatto di leggere del buffer in LE o BE
@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+
+mod commodore;
+pub mod c64;
+pub mod vic20;
+pub mod c128;
+pub mod pet;
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug)]
+pub struct PlatformSymbol {
+    pub address: u32,
+    pub name: &'static str,
+    pub description: &'static str
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Platform {
+    C64,
+    Vic20,
+    C128,
+    Pet,
+}
+
+impl Platform {
+    pub fn symbol_sets(&self) -> &'static [&'static [PlatformSymbol]] {
+        match self {
+            Platform::C64 => c64::SYMBOL_SETS,
+            Platform::Vic20 => vic20::SYMBOL_SETS,
+            Platform::C128 => c128::SYMBOL_SETS,
+            Platform::Pet => pet::SYMBOL_SETS,
+        }
+    }
+
+    pub fn symbols(&self) -> impl Iterator<Item = &'static PlatformSymbol> {
+        self.symbol_sets().iter().flat_map(|set| set.iter())
+    }
+
+    // Word che puntano a codice, come i vettori di avvio del BASIC.
+    pub fn code_vectors(&self) -> &'static [PlatformSymbol] {
+        match self {
+            Platform::C64 => c64::BASIC_VECTORS,
+            Platform::Vic20 => vic20::BASIC_VECTORS,
+            Platform::C128 | Platform::Pet => &[],
+        }
+    }
+
+    // Tabelle di JMP in ROM, ogni voce è un punto di ingresso.
+    pub fn jump_tables(&self) -> &'static [&'static [PlatformSymbol]] {
+        match self {
+            Platform::C64 | Platform::Vic20 => &[commodore::KERNAL_JUMP_TABLE],
+            Platform::C128 => &[c128::KERNAL_JUMP_TABLE_C128, commodore::KERNAL_JUMP_TABLE],
+            Platform::Pet => &[pet::KERNAL_JUMP_TABLE],
+        }
+    }
+
+    // Routine del KERNAL con cui termina un gestore di interrupt installato
+    // dal programma, come JMP $EA31.
+    pub fn interrupt_exits(&self) -> &'static [u32] {
+        match self {
+            Platform::C64 => &[0xEA31, 0xEA81, 0xFEBC],
+            Platform::Vic20 | Platform::C128 | Platform::Pet => &[],
+        }
+    }
+
+    pub fn register_sets(&self) -> &'static [&'static [IoRegister]] {
+        match self {
+            Platform::C64 => c64::REGISTER_SETS,
+            Platform::C128 => c128::REGISTER_SETS,
+            Platform::Vic20 | Platform::Pet => &[],
+        }
+    }
+
+    pub fn registers(&self) -> impl Iterator<Item = &'static IoRegister> {
+        self.register_sets().iter().flat_map(|set| set.iter())
+    }
+}
+
+// Campo di un registro di I/O, decodificato dal valore scritto.
+#[derive(Debug)]
+pub enum BitField {
+    // Bit singolo con il testo per acceso e spento, vuoto per ometterlo.
+    Flag { bit: u8, set: &'static str, clear: &'static str },
+    // Campo numerico reso come NOME=valore.
+    Value { mask: u8, name: &'static str },
+    // Campo che moltiplicato per unit dà un indirizzo.
+    Address { mask: u8, unit: u32, name: &'static str },
+    // Campo che seleziona un nome da una tabella.
+    Named { mask: u8, names: &'static [&'static str] },
+}
+
+impl BitField {
+    fn decode(&self, value: u8) -> String {
+        let field = |mask: u8| (value & mask) >> mask.trailing_zeros();
+        match self {
+            BitField::Flag { bit, set, clear } => if value & (1 << bit) != 0 { set } else { clear }.to_string(),
+            BitField::Value { mask, name } => format!("{}={}", name, field(*mask)),
+            BitField::Address { mask, unit, name } => format!("{} ${:04X}", name, field(*mask) as u32 * unit),
+            BitField::Named { mask, names } => names.get(field(*mask) as usize).copied().unwrap_or_default().to_string(),
+        }
+    }
+}
+
+// Registro di un chip di I/O: cosa fanno una lettura e una scrittura e
+// come si leggono i bit del valore scritto.
+#[derive(Debug)]
+pub struct IoRegister {
+    pub address: u32,
+    pub read: &'static str,
+    pub write: &'static str,
+    pub fields: &'static [BitField]
+}
+
+impl IoRegister {
+    // "25 rows, screen on, YSCROLL=3" per $1B scritto in $D011.
+    pub fn decode(&self, value: u8) -> String {
+        let parts: Vec<String> = self.fields.iter().map(|f| f.decode(value)).filter(|p| !p.is_empty()).collect();
+        parts.join(", ")
+    }
+}
+
+// Costruttore compatto per le tabelle dei simboli.
+const fn sym(address: u32, name: &'static str, description: &'static str) -> PlatformSymbol {
+    PlatformSymbol { address, name, description }
+}
+
+// Costruttore compatto per le tabelle dei registri.
+const fn reg(address: u32, read: &'static str, write: &'static str, fields: &'static [BitField]) -> IoRegister {
+    IoRegister { address, read, write, fields }
+}
+
+const fn flag(bit: u8, set: &'static str, clear: &'static str) -> BitField {
+    BitField::Flag { bit, set, clear }
+}
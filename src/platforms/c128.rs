@@ -0,0 +1,60 @@
+use super::{commodore::{CIA1, CIA1_REGISTERS, CIA2, CIA2_REGISTERS, CPU_VECTORS, KERNAL_JUMP_TABLE, SID, SID_REGISTERS, VIC_II, VIC_II_REGISTERS}, sym, IoRegister, PlatformSymbol};
+
+// Estensioni della tabella di salto del KERNAL del C128.
+pub const KERNAL_JUMP_TABLE_C128: &[PlatformSymbol] = &[
+    sym(0xFF47, "SPIN_SPOUT", "setup fast serial"),
+    sym(0xFF4A, "CLOSE_ALL", "close all files on a device"),
+    sym(0xFF4D, "C64MODE", "switch to C64 mode"),
+    sym(0xFF50, "DMA_CALL", "send command to DMA device"),
+    sym(0xFF53, "BOOT_CALL", "boot from disk"),
+    sym(0xFF56, "PHOENIX", "init cartridges"),
+    sym(0xFF59, "LKUPLA", "search logical file table"),
+    sym(0xFF5C, "LKUPSA", "search secondary address"),
+    sym(0xFF5F, "SWAPPER", "switch 40/80 column screen"),
+    sym(0xFF62, "DLCHR", "init 80 column character set"),
+    sym(0xFF65, "PFKEY", "program function key"),
+    sym(0xFF68, "SETBNK", "set bank for I/O"),
+    sym(0xFF6B, "GETCFG", "bank to MMU configuration"),
+    sym(0xFF6E, "JSRFAR", "JSR to another bank"),
+    sym(0xFF71, "JMPFAR", "JMP to another bank"),
+    sym(0xFF74, "INDFET", "LDA from another bank"),
+    sym(0xFF77, "INDSTA", "STA to another bank"),
+    sym(0xFF7A, "INDCMP", "CMP with another bank"),
+    sym(0xFF7D, "PRIMM", "print immediate string"),
+];
+
+pub const MMU: &[PlatformSymbol] = &[
+    sym(0xD500, "MMUCR", "MMU configuration"),
+    sym(0xD501, "MMUPCRA", "MMU preconfiguration A"),
+    sym(0xD502, "MMUPCRB", "MMU preconfiguration B"),
+    sym(0xD503, "MMUPCRC", "MMU preconfiguration C"),
+    sym(0xD504, "MMUPCRD", "MMU preconfiguration D"),
+    sym(0xD505, "MMUMCR", "MMU mode configuration"),
+    sym(0xD506, "MMURCR", "MMU RAM configuration"),
+    sym(0xD507, "MMUP0L", "MMU page 0 pointer lo"),
+    sym(0xD508, "MMUP0H", "MMU page 0 pointer hi"),
+    sym(0xD509, "MMUP1L", "MMU page 1 pointer lo"),
+    sym(0xD50A, "MMUP1H", "MMU page 1 pointer hi"),
+    sym(0xD50B, "MMUVR", "MMU version"),
+    sym(0xD600, "VDCADR", "VDC register select"),
+    sym(0xD601, "VDCDAT", "VDC register data"),
+    sym(0xFF00, "MMULCR", "MMU load configuration"),
+];
+
+pub const SYMBOL_SETS: &[&[PlatformSymbol]] = &[
+    KERNAL_JUMP_TABLE_C128,
+    KERNAL_JUMP_TABLE,
+    CPU_VECTORS,
+    VIC_II,
+    SID,
+    MMU,
+    CIA1,
+    CIA2,
+];
+
+pub const REGISTER_SETS: &[&[IoRegister]] = &[
+    VIC_II_REGISTERS,
+    SID_REGISTERS,
+    CIA1_REGISTERS,
+    CIA2_REGISTERS,
+];
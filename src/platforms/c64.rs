@@ -0,0 +1,31 @@
+use super::{commodore::{CIA1, CIA1_REGISTERS, CIA2, CIA2_REGISTERS, CPU_VECTORS, KERNAL_JUMP_TABLE, RAM_VECTORS, SID, SID_REGISTERS, VIC_II, VIC_II_REGISTERS, ZERO_PAGE}, sym, IoRegister, PlatformSymbol};
+
+pub const BASIC_VECTORS: &[PlatformSymbol] = &[
+    sym(0xA000, "BASIC_COLD", "BASIC cold start vector"),
+    sym(0xA002, "BASIC_WARM", "BASIC warm start vector"),
+];
+
+pub const ZERO_PAGE_C64: &[PlatformSymbol] = &[
+    sym(0x00, "D6510", "6510 data direction register"),
+    sym(0x01, "R6510", "6510 I/O port, memory configuration"),
+];
+
+pub const SYMBOL_SETS: &[&[PlatformSymbol]] = &[
+    KERNAL_JUMP_TABLE,
+    CPU_VECTORS,
+    BASIC_VECTORS,
+    RAM_VECTORS,
+    ZERO_PAGE_C64,
+    ZERO_PAGE,
+    VIC_II,
+    SID,
+    CIA1,
+    CIA2,
+];
+
+pub const REGISTER_SETS: &[&[IoRegister]] = &[
+    VIC_II_REGISTERS,
+    SID_REGISTERS,
+    CIA1_REGISTERS,
+    CIA2_REGISTERS,
+];
@@ -0,0 +1,366 @@
+// Tabelle condivise tra le macchine Commodore.
+use super::{flag, reg, sym, BitField, IoRegister, PlatformSymbol};
+
+// Tabella di salto del KERNAL comune a VIC-20, C64 e C128.
+pub const KERNAL_JUMP_TABLE: &[PlatformSymbol] = &[
+    sym(0xFF81, "CINT", "init screen editor and VIC"),
+    sym(0xFF84, "IOINIT", "init I/O devices"),
+    sym(0xFF87, "RAMTAS", "test RAM, set top and bottom of memory"),
+    sym(0xFF8A, "RESTOR", "restore default I/O vectors"),
+    sym(0xFF8D, "VECTOR", "read/set I/O vector table"),
+    sym(0xFF90, "SETMSG", "control KERNAL messages"),
+    sym(0xFF93, "SECOND", "send secondary address after LISTEN"),
+    sym(0xFF96, "TKSA", "send secondary address after TALK"),
+    sym(0xFF99, "MEMTOP", "read/set top of memory"),
+    sym(0xFF9C, "MEMBOT", "read/set bottom of memory"),
+    sym(0xFF9F, "SCNKEY", "scan keyboard"),
+    sym(0xFFA2, "SETTMO", "set IEEE timeout"),
+    sym(0xFFA5, "ACPTR", "input byte from serial bus"),
+    sym(0xFFA8, "CIOUT", "output byte to serial bus"),
+    sym(0xFFAB, "UNTLK", "send UNTALK"),
+    sym(0xFFAE, "UNLSN", "send UNLISTEN"),
+    sym(0xFFB1, "LISTEN", "send LISTEN"),
+    sym(0xFFB4, "TALK", "send TALK"),
+    sym(0xFFB7, "READST", "read I/O status"),
+    sym(0xFFBA, "SETLFS", "set logical, device and secondary address"),
+    sym(0xFFBD, "SETNAM", "set file name"),
+    sym(0xFFC0, "OPEN", "open logical file"),
+    sym(0xFFC3, "CLOSE", "close logical file"),
+    sym(0xFFC6, "CHKIN", "open channel for input"),
+    sym(0xFFC9, "CHKOUT", "open channel for output"),
+    sym(0xFFCC, "CLRCHN", "restore default I/O channels"),
+    sym(0xFFCF, "CHRIN", "input character"),
+    sym(0xFFD2, "CHROUT", "output character"),
+    sym(0xFFD5, "LOAD", "load or verify from device"),
+    sym(0xFFD8, "SAVE", "save to device"),
+    sym(0xFFDB, "SETTIM", "set jiffy clock"),
+    sym(0xFFDE, "RDTIM", "read jiffy clock"),
+    sym(0xFFE1, "STOP", "check STOP key"),
+    sym(0xFFE4, "GETIN", "get character from keyboard buffer"),
+    sym(0xFFE7, "CLALL", "close all files"),
+    sym(0xFFEA, "UDTIM", "update jiffy clock"),
+    sym(0xFFED, "SCREEN", "return screen size"),
+    sym(0xFFF0, "PLOT", "read/set cursor position"),
+    sym(0xFFF3, "IOBASE", "return I/O base address"),
+];
+
+pub const CPU_VECTORS: &[PlatformSymbol] = &[
+    sym(0xFFFA, "NMI_VECTOR", "NMI vector"),
+    sym(0xFFFC, "RESET_VECTOR", "RESET vector"),
+    sym(0xFFFE, "IRQ_VECTOR", "IRQ/BRK vector"),
+];
+
+// Vettori in RAM di KERNAL e BASIC, stessi indirizzi su VIC-20 e C64.
+pub const RAM_VECTORS: &[PlatformSymbol] = &[
+    sym(0x0300, "IERROR", "BASIC error message vector"),
+    sym(0x0302, "IMAIN", "BASIC warm start vector"),
+    sym(0x0304, "ICRNCH", "BASIC tokenizer vector"),
+    sym(0x0306, "IQPLOP", "BASIC token lister vector"),
+    sym(0x0308, "IGONE", "BASIC statement dispatch vector"),
+    sym(0x030A, "IEVAL", "BASIC expression evaluation vector"),
+    sym(0x0314, "CINV", "IRQ vector"),
+    sym(0x0316, "CBINV", "BRK vector"),
+    sym(0x0318, "NMINV", "NMI vector"),
+    sym(0x031A, "IOPEN", "OPEN vector"),
+    sym(0x031C, "ICLOSE", "CLOSE vector"),
+    sym(0x031E, "ICHKIN", "CHKIN vector"),
+    sym(0x0320, "ICKOUT", "CHKOUT vector"),
+    sym(0x0322, "ICLRCH", "CLRCHN vector"),
+    sym(0x0324, "IBASIN", "CHRIN vector"),
+    sym(0x0326, "IBSOUT", "CHROUT vector"),
+    sym(0x0328, "ISTOP", "STOP vector"),
+    sym(0x032A, "IGETIN", "GETIN vector"),
+    sym(0x032C, "ICLALL", "CLALL vector"),
+    sym(0x032E, "USRCMD", "user defined vector"),
+    sym(0x0330, "ILOAD", "LOAD vector"),
+    sym(0x0332, "ISAVE", "SAVE vector"),
+];
+
+// Variabili in pagina zero comuni a VIC-20 e C64.
+pub const ZERO_PAGE: &[PlatformSymbol] = &[
+    sym(0x2B, "TXTTAB", "start of BASIC text"),
+    sym(0x2D, "VARTAB", "start of BASIC variables"),
+    sym(0x2F, "ARYTAB", "start of BASIC arrays"),
+    sym(0x31, "STREND", "end of BASIC arrays"),
+    sym(0x33, "FRETOP", "bottom of string storage"),
+    sym(0x37, "MEMSIZ", "top of BASIC memory"),
+    sym(0x39, "CURLIN", "current BASIC line number"),
+    sym(0x7A, "TXTPTR", "pointer into BASIC text"),
+    sym(0x90, "STATUS", "I/O status word"),
+    sym(0x91, "STKEY", "STOP key flag"),
+    sym(0x93, "VERCK", "LOAD/VERIFY flag"),
+    sym(0x99, "DFLTN", "default input device"),
+    sym(0x9A, "DFLTO", "default output device"),
+    sym(0x9D, "MSGFLG", "KERNAL message control"),
+    sym(0xA0, "TIME", "jiffy clock"),
+    sym(0xB7, "FNLEN", "file name length"),
+    sym(0xB8, "LA", "current logical file"),
+    sym(0xB9, "SA", "current secondary address"),
+    sym(0xBA, "FA", "current device number"),
+    sym(0xBB, "FNADR", "pointer to file name"),
+    sym(0xC6, "NDX", "keyboard buffer length"),
+    sym(0xC7, "RVS", "reverse mode flag"),
+    sym(0xCC, "BLNSW", "cursor blink enable"),
+    sym(0xD1, "PNT", "pointer to current screen line"),
+    sym(0xD3, "PNTR", "cursor column"),
+    sym(0xD4, "QTSW", "quote mode flag"),
+    sym(0xD6, "TBLX", "cursor row"),
+    sym(0xF3, "USER", "pointer to current colour RAM line"),
+    sym(0xF5, "KEYTAB", "keyboard decode table pointer"),
+];
+
+pub const VIC_II: &[PlatformSymbol] = &[
+    sym(0xD000, "SP0X", "sprite 0 X"),
+    sym(0xD001, "SP0Y", "sprite 0 Y"),
+    sym(0xD002, "SP1X", "sprite 1 X"),
+    sym(0xD003, "SP1Y", "sprite 1 Y"),
+    sym(0xD004, "SP2X", "sprite 2 X"),
+    sym(0xD005, "SP2Y", "sprite 2 Y"),
+    sym(0xD006, "SP3X", "sprite 3 X"),
+    sym(0xD007, "SP3Y", "sprite 3 Y"),
+    sym(0xD008, "SP4X", "sprite 4 X"),
+    sym(0xD009, "SP4Y", "sprite 4 Y"),
+    sym(0xD00A, "SP5X", "sprite 5 X"),
+    sym(0xD00B, "SP5Y", "sprite 5 Y"),
+    sym(0xD00C, "SP6X", "sprite 6 X"),
+    sym(0xD00D, "SP6Y", "sprite 6 Y"),
+    sym(0xD00E, "SP7X", "sprite 7 X"),
+    sym(0xD00F, "SP7Y", "sprite 7 Y"),
+    sym(0xD010, "MSIGX", "sprite X bit 8"),
+    sym(0xD011, "SCROLY", "VIC control 1, Y scroll"),
+    sym(0xD012, "RASTER", "raster line"),
+    sym(0xD013, "LPENX", "light pen X"),
+    sym(0xD014, "LPENY", "light pen Y"),
+    sym(0xD015, "SPENA", "sprite enable"),
+    sym(0xD016, "SCROLX", "VIC control 2, X scroll"),
+    sym(0xD017, "YXPAND", "sprite Y expand"),
+    sym(0xD018, "VMCSB", "screen and charset base"),
+    sym(0xD019, "VICIRQ", "VIC interrupt flags"),
+    sym(0xD01A, "IRQMSK", "VIC interrupt enable"),
+    sym(0xD01B, "SPBGPR", "sprite to background priority"),
+    sym(0xD01C, "SPMC", "sprite multicolour"),
+    sym(0xD01D, "XXPAND", "sprite X expand"),
+    sym(0xD01E, "SPSPCL", "sprite-sprite collision"),
+    sym(0xD01F, "SPBGCL", "sprite-background collision"),
+    sym(0xD020, "EXTCOL", "border colour"),
+    sym(0xD021, "BGCOL0", "background colour 0"),
+    sym(0xD022, "BGCOL1", "background colour 1"),
+    sym(0xD023, "BGCOL2", "background colour 2"),
+    sym(0xD024, "BGCOL3", "background colour 3"),
+    sym(0xD025, "SPMC0", "sprite multicolour 0"),
+    sym(0xD026, "SPMC1", "sprite multicolour 1"),
+    sym(0xD027, "SP0COL", "sprite 0 colour"),
+    sym(0xD028, "SP1COL", "sprite 1 colour"),
+    sym(0xD029, "SP2COL", "sprite 2 colour"),
+    sym(0xD02A, "SP3COL", "sprite 3 colour"),
+    sym(0xD02B, "SP4COL", "sprite 4 colour"),
+    sym(0xD02C, "SP5COL", "sprite 5 colour"),
+    sym(0xD02D, "SP6COL", "sprite 6 colour"),
+    sym(0xD02E, "SP7COL", "sprite 7 colour"),
+];
+
+pub const SID: &[PlatformSymbol] = &[
+    sym(0xD400, "FRELO1", "voice 1 frequency lo"),
+    sym(0xD401, "FREHI1", "voice 1 frequency hi"),
+    sym(0xD402, "PWLO1", "voice 1 pulse width lo"),
+    sym(0xD403, "PWHI1", "voice 1 pulse width hi"),
+    sym(0xD404, "VCREG1", "voice 1 control"),
+    sym(0xD405, "ATDCY1", "voice 1 attack/decay"),
+    sym(0xD406, "SUREL1", "voice 1 sustain/release"),
+    sym(0xD407, "FRELO2", "voice 2 frequency lo"),
+    sym(0xD408, "FREHI2", "voice 2 frequency hi"),
+    sym(0xD409, "PWLO2", "voice 2 pulse width lo"),
+    sym(0xD40A, "PWHI2", "voice 2 pulse width hi"),
+    sym(0xD40B, "VCREG2", "voice 2 control"),
+    sym(0xD40C, "ATDCY2", "voice 2 attack/decay"),
+    sym(0xD40D, "SUREL2", "voice 2 sustain/release"),
+    sym(0xD40E, "FRELO3", "voice 3 frequency lo"),
+    sym(0xD40F, "FREHI3", "voice 3 frequency hi"),
+    sym(0xD410, "PWLO3", "voice 3 pulse width lo"),
+    sym(0xD411, "PWHI3", "voice 3 pulse width hi"),
+    sym(0xD412, "VCREG3", "voice 3 control"),
+    sym(0xD413, "ATDCY3", "voice 3 attack/decay"),
+    sym(0xD414, "SUREL3", "voice 3 sustain/release"),
+    sym(0xD415, "CUTLO", "filter cutoff lo"),
+    sym(0xD416, "CUTHI", "filter cutoff hi"),
+    sym(0xD417, "RESON", "filter resonance and routing"),
+    sym(0xD418, "SIGVOL", "filter mode and volume"),
+    sym(0xD419, "POTX", "paddle X"),
+    sym(0xD41A, "POTY", "paddle Y"),
+    sym(0xD41B, "RANDOM", "voice 3 oscillator"),
+    sym(0xD41C, "ENV3", "voice 3 envelope"),
+];
+
+pub const CIA1: &[PlatformSymbol] = &[
+    sym(0xDC00, "CIAPRA", "CIA1 port A, keyboard columns / joystick 2"),
+    sym(0xDC01, "CIAPRB", "CIA1 port B, keyboard rows / joystick 1"),
+    sym(0xDC02, "CIDDRA", "CIA1 data direction A"),
+    sym(0xDC03, "CIDDRB", "CIA1 data direction B"),
+    sym(0xDC04, "TIMALO", "CIA1 timer A lo"),
+    sym(0xDC05, "TIMAHI", "CIA1 timer A hi"),
+    sym(0xDC06, "TIMBLO", "CIA1 timer B lo"),
+    sym(0xDC07, "TIMBHI", "CIA1 timer B hi"),
+    sym(0xDC08, "TODTEN", "CIA1 TOD tenths"),
+    sym(0xDC09, "TODSEC", "CIA1 TOD seconds"),
+    sym(0xDC0A, "TODMIN", "CIA1 TOD minutes"),
+    sym(0xDC0B, "TODHRS", "CIA1 TOD hours"),
+    sym(0xDC0C, "CIASDR", "CIA1 serial data"),
+    sym(0xDC0D, "CIAICR", "CIA1 interrupt control"),
+    sym(0xDC0E, "CIACRA", "CIA1 control A"),
+    sym(0xDC0F, "CIACRB", "CIA1 control B"),
+];
+
+pub const CIA2: &[PlatformSymbol] = &[
+    sym(0xDD00, "CI2PRA", "CIA2 port A, VIC bank / serial bus"),
+    sym(0xDD01, "CI2PRB", "CIA2 port B, user port"),
+    sym(0xDD02, "C2DDRA", "CIA2 data direction A"),
+    sym(0xDD03, "C2DDRB", "CIA2 data direction B"),
+    sym(0xDD04, "TI2ALO", "CIA2 timer A lo"),
+    sym(0xDD05, "TI2AHI", "CIA2 timer A hi"),
+    sym(0xDD06, "TI2BLO", "CIA2 timer B lo"),
+    sym(0xDD07, "TI2BHI", "CIA2 timer B hi"),
+    sym(0xDD08, "TO2TEN", "CIA2 TOD tenths"),
+    sym(0xDD09, "TO2SEC", "CIA2 TOD seconds"),
+    sym(0xDD0A, "TO2MIN", "CIA2 TOD minutes"),
+    sym(0xDD0B, "TO2HRS", "CIA2 TOD hours"),
+    sym(0xDD0C, "CI2SDR", "CIA2 serial data"),
+    sym(0xDD0D, "CI2ICR", "CIA2 NMI control"),
+    sym(0xDD0E, "CI2CRA", "CIA2 control A"),
+    sym(0xDD0F, "CI2CRB", "CIA2 control B"),
+];
+
+const COLOURS: &[&str] = &[
+    "black", "white", "red", "cyan", "purple", "green", "blue", "yellow",
+    "orange", "brown", "light red", "dark grey", "grey", "light green", "light blue", "light grey",
+];
+const COLOUR: &[BitField] = &[BitField::Named { mask: 0x0F, names: COLOURS }];
+
+const VIC_INTERRUPTS: &[BitField] = &[
+    flag(0, "raster", ""),
+    flag(1, "sprite-background", ""),
+    flag(2, "sprite-sprite", ""),
+    flag(3, "light pen", ""),
+];
+
+// Registri del VIC-II di C64 e C128.
+pub const VIC_II_REGISTERS: &[IoRegister] = &[
+    reg(0xD011, "VIC control 1", "VIC control 1", &[
+        flag(3, "25 rows", "24 rows"),
+        flag(4, "screen on", "screen off"),
+        flag(5, "bitmap mode", ""),
+        flag(6, "extended colour", ""),
+        flag(7, "raster bit 8", ""),
+        BitField::Value { mask: 0x07, name: "YSCROLL" },
+    ]),
+    reg(0xD012, "current raster line", "raster interrupt line", &[]),
+    reg(0xD015, "sprite enable", "sprite enable", &[]),
+    reg(0xD016, "VIC control 2", "VIC control 2", &[
+        flag(3, "40 columns", "38 columns"),
+        flag(4, "multicolour", ""),
+        BitField::Value { mask: 0x07, name: "XSCROLL" },
+    ]),
+    reg(0xD018, "VIC memory setup", "VIC memory setup", &[
+        BitField::Address { mask: 0xF0, unit: 0x400, name: "screen" },
+        BitField::Address { mask: 0x0E, unit: 0x800, name: "charset" },
+    ]),
+    reg(0xD019, "VIC interrupt flags", "acknowledge VIC interrupts", VIC_INTERRUPTS),
+    reg(0xD01A, "VIC interrupt enable", "VIC interrupt enable", VIC_INTERRUPTS),
+    reg(0xD01E, "sprite-sprite collisions, cleared on read", "sprite-sprite collisions", &[]),
+    reg(0xD01F, "sprite-background collisions, cleared on read", "sprite-background collisions", &[]),
+    reg(0xD020, "border colour", "border colour", COLOUR),
+    reg(0xD021, "background colour 0", "background colour 0", COLOUR),
+    reg(0xD022, "background colour 1", "background colour 1", COLOUR),
+    reg(0xD023, "background colour 2", "background colour 2", COLOUR),
+    reg(0xD024, "background colour 3", "background colour 3", COLOUR),
+    reg(0xD025, "sprite multicolour 0", "sprite multicolour 0", COLOUR),
+    reg(0xD026, "sprite multicolour 1", "sprite multicolour 1", COLOUR),
+    reg(0xD027, "sprite 0 colour", "sprite 0 colour", COLOUR),
+    reg(0xD028, "sprite 1 colour", "sprite 1 colour", COLOUR),
+    reg(0xD029, "sprite 2 colour", "sprite 2 colour", COLOUR),
+    reg(0xD02A, "sprite 3 colour", "sprite 3 colour", COLOUR),
+    reg(0xD02B, "sprite 4 colour", "sprite 4 colour", COLOUR),
+    reg(0xD02C, "sprite 5 colour", "sprite 5 colour", COLOUR),
+    reg(0xD02D, "sprite 6 colour", "sprite 6 colour", COLOUR),
+    reg(0xD02E, "sprite 7 colour", "sprite 7 colour", COLOUR),
+];
+
+const VOICE_CONTROL: &[BitField] = &[
+    flag(0, "gate on", "gate off"),
+    flag(1, "sync", ""),
+    flag(2, "ring mod", ""),
+    flag(3, "test", ""),
+    flag(4, "triangle", ""),
+    flag(5, "sawtooth", ""),
+    flag(6, "pulse", ""),
+    flag(7, "noise", ""),
+];
+const ATTACK_DECAY: &[BitField] = &[
+    BitField::Value { mask: 0xF0, name: "attack" },
+    BitField::Value { mask: 0x0F, name: "decay" },
+];
+const SUSTAIN_RELEASE: &[BitField] = &[
+    BitField::Value { mask: 0xF0, name: "sustain" },
+    BitField::Value { mask: 0x0F, name: "release" },
+];
+
+// Registri del SID, in sola scrittura tranne gli ultimi quattro.
+pub const SID_REGISTERS: &[IoRegister] = &[
+    reg(0xD404, "voice 1 control", "voice 1 control", VOICE_CONTROL),
+    reg(0xD405, "voice 1 attack/decay", "voice 1 attack/decay", ATTACK_DECAY),
+    reg(0xD406, "voice 1 sustain/release", "voice 1 sustain/release", SUSTAIN_RELEASE),
+    reg(0xD40B, "voice 2 control", "voice 2 control", VOICE_CONTROL),
+    reg(0xD40C, "voice 2 attack/decay", "voice 2 attack/decay", ATTACK_DECAY),
+    reg(0xD40D, "voice 2 sustain/release", "voice 2 sustain/release", SUSTAIN_RELEASE),
+    reg(0xD412, "voice 3 control", "voice 3 control", VOICE_CONTROL),
+    reg(0xD413, "voice 3 attack/decay", "voice 3 attack/decay", ATTACK_DECAY),
+    reg(0xD414, "voice 3 sustain/release", "voice 3 sustain/release", SUSTAIN_RELEASE),
+    reg(0xD417, "filter resonance and routing", "filter resonance and routing", &[
+        BitField::Value { mask: 0xF0, name: "resonance" },
+        flag(0, "filter voice 1", ""),
+        flag(1, "filter voice 2", ""),
+        flag(2, "filter voice 3", ""),
+        flag(3, "filter external", ""),
+    ]),
+    reg(0xD418, "filter mode and volume", "filter mode and volume", &[
+        BitField::Value { mask: 0x0F, name: "volume" },
+        flag(4, "low pass", ""),
+        flag(5, "band pass", ""),
+        flag(6, "high pass", ""),
+        flag(7, "voice 3 off", ""),
+    ]),
+    reg(0xD419, "paddle X", "paddle X", &[]),
+    reg(0xD41A, "paddle Y", "paddle Y", &[]),
+    reg(0xD41B, "voice 3 oscillator, random numbers", "voice 3 oscillator", &[]),
+    reg(0xD41C, "voice 3 envelope", "voice 3 envelope", &[]),
+];
+
+const CIA_INTERRUPTS: &[BitField] = &[
+    flag(7, "enable", "disable"),
+    flag(0, "timer A", ""),
+    flag(1, "timer B", ""),
+    flag(2, "TOD alarm", ""),
+    flag(3, "serial", ""),
+    flag(4, "FLAG line", ""),
+];
+const CIA_TIMER_CONTROL: &[BitField] = &[
+    flag(0, "start", "stop"),
+    flag(3, "one shot", "continuous"),
+    flag(4, "force load", ""),
+];
+
+pub const CIA1_REGISTERS: &[IoRegister] = &[
+    reg(0xDC00, "joystick 2 / keyboard columns", "select keyboard columns", &[]),
+    reg(0xDC01, "keyboard rows / joystick 1", "keyboard rows", &[]),
+    reg(0xDC0D, "CIA1 interrupt flags, acknowledged on read", "CIA1 interrupt mask", CIA_INTERRUPTS),
+    reg(0xDC0E, "CIA1 timer A control", "CIA1 timer A control", CIA_TIMER_CONTROL),
+    reg(0xDC0F, "CIA1 timer B control", "CIA1 timer B control", CIA_TIMER_CONTROL),
+];
+
+pub const CIA2_REGISTERS: &[IoRegister] = &[
+    reg(0xDD00, "VIC bank / serial bus", "VIC bank / serial bus", &[
+        BitField::Named { mask: 0x03, names: &["VIC bank $C000", "VIC bank $8000", "VIC bank $4000", "VIC bank $0000"] },
+    ]),
+    reg(0xDD0D, "CIA2 NMI flags, acknowledged on read", "CIA2 NMI mask", CIA_INTERRUPTS),
+    reg(0xDD0E, "CIA2 timer A control", "CIA2 timer A control", CIA_TIMER_CONTROL),
+    reg(0xDD0F, "CIA2 timer B control", "CIA2 timer B control", CIA_TIMER_CONTROL),
+];
@@ -0,0 +1,58 @@
+use super::{commodore::CPU_VECTORS, sym, PlatformSymbol};
+
+pub const KERNAL_JUMP_TABLE: &[PlatformSymbol] = &[
+    sym(0xFFC0, "OPEN", "open logical file"),
+    sym(0xFFC3, "CLOSE", "close logical file"),
+    sym(0xFFC6, "CHKIN", "open channel for input"),
+    sym(0xFFC9, "CHKOUT", "open channel for output"),
+    sym(0xFFCC, "CLRCHN", "restore default I/O channels"),
+    sym(0xFFCF, "CHRIN", "input character"),
+    sym(0xFFD2, "CHROUT", "output character"),
+    sym(0xFFD5, "LOAD", "load from device"),
+    sym(0xFFD8, "SAVE", "save to device"),
+    sym(0xFFDB, "VERIFY", "verify against device"),
+    sym(0xFFDE, "SYS", "call machine code"),
+    sym(0xFFE1, "STOP", "check STOP key"),
+    sym(0xFFE4, "GETIN", "get character from keyboard buffer"),
+    sym(0xFFE7, "CLALL", "close all files"),
+    sym(0xFFEA, "UDTIM", "update jiffy clock"),
+];
+
+pub const IO: &[PlatformSymbol] = &[
+    sym(0xE810, "PIA1PA", "PIA1 port A, keyboard row select"),
+    sym(0xE811, "PIA1CRA", "PIA1 control A"),
+    sym(0xE812, "PIA1PB", "PIA1 port B, keyboard row"),
+    sym(0xE813, "PIA1CRB", "PIA1 control B, retrace interrupt"),
+    sym(0xE820, "PIA2PA", "PIA2 port A, IEEE data in"),
+    sym(0xE821, "PIA2CRA", "PIA2 control A"),
+    sym(0xE822, "PIA2PB", "PIA2 port B, IEEE data out"),
+    sym(0xE823, "PIA2CRB", "PIA2 control B"),
+    sym(0xE840, "VIAPB", "VIA port B"),
+    sym(0xE841, "VIAPA", "VIA port A, user port"),
+    sym(0xE842, "VIADDRB", "VIA data direction B"),
+    sym(0xE843, "VIADDRA", "VIA data direction A"),
+    sym(0xE844, "VIAT1CL", "VIA timer 1 lo"),
+    sym(0xE845, "VIAT1CH", "VIA timer 1 hi"),
+    sym(0xE84B, "VIAACR", "VIA auxiliary control"),
+    sym(0xE84C, "VIAPCR", "VIA peripheral control"),
+    sym(0xE84D, "VIAIFR", "VIA interrupt flags"),
+    sym(0xE84E, "VIAIER", "VIA interrupt enable"),
+    sym(0xE880, "CRTCADR", "CRTC register select"),
+    sym(0xE881, "CRTCDAT", "CRTC register data"),
+];
+
+pub const ZERO_PAGE: &[PlatformSymbol] = &[
+    sym(0x28, "TXTTAB", "start of BASIC text"),
+    sym(0x2A, "VARTAB", "start of BASIC variables"),
+    sym(0x34, "MEMSIZ", "top of BASIC memory"),
+    sym(0x77, "TXTPTR", "pointer into BASIC text"),
+    sym(0x96, "STATUS", "I/O status word"),
+    sym(0x9E, "NDX", "keyboard buffer length"),
+];
+
+pub const SYMBOL_SETS: &[&[PlatformSymbol]] = &[
+    KERNAL_JUMP_TABLE,
+    CPU_VECTORS,
+    IO,
+    ZERO_PAGE,
+];
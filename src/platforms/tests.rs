@@ -0,0 +1,34 @@
+use std::collections::HashSet;
+
+use super::Platform;
+
+#[test]
+fn platform_symbols_are_unique() {
+    for platform in [Platform::C64, Platform::Vic20, Platform::C128, Platform::Pet] {
+        let mut addresses = HashSet::new();
+        let mut names = HashSet::new();
+        for symbol in platform.symbols() {
+            assert!(addresses.insert(symbol.address), "{:?}: duplicate address ${:04X}", platform, symbol.address);
+            assert!(names.insert(symbol.name), "{:?}: duplicate name {}", platform, symbol.name);
+        }
+    }
+}
+
+#[test]
+fn kernal_is_shared() {
+    let chrout = |platform: Platform| platform.symbols().find(|s| s.name == "CHROUT").map(|s| s.address);
+    assert_eq!(chrout(Platform::C64), Some(0xFFD2));
+    assert_eq!(chrout(Platform::Vic20), Some(0xFFD2));
+    assert_eq!(chrout(Platform::Pet), Some(0xFFD2));
+    assert!(Platform::Vic20.symbols().all(|s| s.address != 0xD020));
+}
+
+#[test]
+fn io_registers_decode_written_values() {
+    let register = |address: u32| Platform::C64.registers().find(|r| r.address == address).unwrap();
+    assert_eq!(register(0xD011).decode(0x1B), "25 rows, screen on, YSCROLL=3");
+    assert_eq!(register(0xD018).decode(0x15), "screen $0400, charset $1000");
+    assert_eq!(register(0xD404).decode(0x41), "gate on, pulse");
+    assert_eq!(register(0xDD00).decode(0x97), "VIC bank $0000");
+    assert_eq!(Platform::Vic20.registers().count(), 0);
+}
@@ -0,0 +1,58 @@
+use super::{commodore::{CPU_VECTORS, KERNAL_JUMP_TABLE, RAM_VECTORS, ZERO_PAGE}, sym, PlatformSymbol};
+
+pub const BASIC_VECTORS: &[PlatformSymbol] = &[
+    sym(0xC000, "BASIC_COLD", "BASIC cold start vector"),
+    sym(0xC002, "BASIC_WARM", "BASIC warm start vector"),
+];
+
+pub const VIC: &[PlatformSymbol] = &[
+    sym(0x9000, "VICCR0", "left margin, interlace"),
+    sym(0x9001, "VICCR1", "top margin"),
+    sym(0x9002, "VICCR2", "columns, video matrix bit 9"),
+    sym(0x9003, "VICCR3", "rows, character size, raster bit 0"),
+    sym(0x9004, "VICCR4", "raster line"),
+    sym(0x9005, "VICCR5", "screen and character memory"),
+    sym(0x9006, "VICCR6", "light pen X"),
+    sym(0x9007, "VICCR7", "light pen Y"),
+    sym(0x9008, "VICCR8", "paddle X"),
+    sym(0x9009, "VICCR9", "paddle Y"),
+    sym(0x900A, "VICCRA", "bass oscillator"),
+    sym(0x900B, "VICCRB", "alto oscillator"),
+    sym(0x900C, "VICCRC", "soprano oscillator"),
+    sym(0x900D, "VICCRD", "noise oscillator"),
+    sym(0x900E, "VICCRE", "auxiliary colour, volume"),
+    sym(0x900F, "VICCRF", "screen and border colour"),
+];
+
+pub const VIAS: &[PlatformSymbol] = &[
+    sym(0x9110, "VIA1PB", "VIA1 port B, user port"),
+    sym(0x9111, "VIA1PA1", "VIA1 port A, serial bus / joystick"),
+    sym(0x9112, "VIA1DDRB", "VIA1 data direction B"),
+    sym(0x9113, "VIA1DDRA", "VIA1 data direction A"),
+    sym(0x9114, "VIA1T1CL", "VIA1 timer 1 lo"),
+    sym(0x9115, "VIA1T1CH", "VIA1 timer 1 hi"),
+    sym(0x911B, "VIA1ACR", "VIA1 auxiliary control"),
+    sym(0x911C, "VIA1PCR", "VIA1 peripheral control"),
+    sym(0x911D, "VIA1IFR", "VIA1 interrupt flags"),
+    sym(0x911E, "VIA1IER", "VIA1 interrupt enable"),
+    sym(0x9120, "VIA2PB", "VIA2 port B, keyboard columns"),
+    sym(0x9121, "VIA2PA1", "VIA2 port A, keyboard rows"),
+    sym(0x9122, "VIA2DDRB", "VIA2 data direction B"),
+    sym(0x9123, "VIA2DDRA", "VIA2 data direction A"),
+    sym(0x9124, "VIA2T1CL", "VIA2 timer 1 lo"),
+    sym(0x9125, "VIA2T1CH", "VIA2 timer 1 hi"),
+    sym(0x912B, "VIA2ACR", "VIA2 auxiliary control"),
+    sym(0x912C, "VIA2PCR", "VIA2 peripheral control"),
+    sym(0x912D, "VIA2IFR", "VIA2 interrupt flags"),
+    sym(0x912E, "VIA2IER", "VIA2 interrupt enable"),
+];
+
+pub const SYMBOL_SETS: &[&[PlatformSymbol]] = &[
+    KERNAL_JUMP_TABLE,
+    CPU_VECTORS,
+    BASIC_VECTORS,
+    RAM_VECTORS,
+    ZERO_PAGE,
+    VIC,
+    VIAS,
+];
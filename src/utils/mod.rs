@@ -1,52 +1,9 @@
 use std::collections::HashMap;
 
-#[derive(Debug, Default)]
-pub struct AsciiReference {
-    offset: u32,
-    ascii: String
-}
+#[cfg(test)]
+mod tests;
 
-impl AsciiReference {
-    pub fn new() -> Self {
-        AsciiReference {
-            offset: 0,
-            ascii: String::from("")
-        }
-    }
-    
-    pub fn from(offset: u32, ascii: String) -> Self {
-        AsciiReference {
-            offset,
-            ascii
-        }
-    }
-}
-
-pub type AsciiReferences = Vec<AsciiReference>;
-
-pub fn extract_ascii_references(bytes: &Vec<u8>, min_chars:u8) -> AsciiReferences {
-    let mut offset:u32 = 0;
-    let mut possible_str:String = String::from("");
-    let mut refs:AsciiReferences = AsciiReferences::new();
-
-    for byte in bytes.iter() {
-        if *byte >= 32 && *byte <=126 {
-            possible_str.push(*byte as char);
-        }else{
-            if possible_str.len() > min_chars.into() {
-                //let entropy=calculate_entropy(&possible_str);
-                //println!("{} -> {}",possible_str,entropy);
-                refs.push(AsciiReference::from(offset, possible_str));
-            }
-            possible_str= String::from("");
-        }
-        offset+=1;
-    }
-
-    refs
-}
-
-fn calculate_entropy(input: &str) -> f64 {
+pub fn calculate_entropy(input: &str) -> f64 {
     let length = input.len();
     let mut char_count_map: HashMap<char, usize> = HashMap::new();
 
@@ -64,3 +21,197 @@ fn calculate_entropy(input: &str) -> f64 {
 
     entropy
 }
+
+// Entropia di Shannon in bit per byte.
+pub fn byte_entropy(bytes: &[u8]) -> f64 {
+    let mut counts = [0usize; 256];
+    for byte in bytes {
+        counts[*byte as usize] += 1;
+    }
+
+    counts.iter().filter(|c| **c > 0).fold(0.0, |entropy, count| {
+        let frequency = *count as f64 / bytes.len() as f64;
+        entropy - frequency * frequency.log2()
+    })
+}
+
+// Carattere stampabile equivalente a un byte PETSCII, None se non ha
+// una rappresentazione sicura dentro una stringa tra virgolette.
+pub fn petscii_to_ascii(byte: u8) -> Option<char> {
+    match byte {
+        0x22 => None,
+        0x20..=0x5B | 0x5D => Some(byte as char),
+        _ => None
+    }
+}
+
+// Come petscii_to_ascii ma per i codici schermo del C64.
+pub fn screen_code_to_ascii(byte: u8) -> Option<char> {
+    match byte {
+        0x00..=0x1B | 0x1D => Some((byte + 0x40) as char),
+        0x22 => None,
+        0x20..=0x3F => Some(byte as char),
+        _ => None
+    }
+}
+
+// CRC-32 (IEEE 802.3), lo stesso usato per identificare le ROM.
+pub fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc: u32 = 0xFFFFFFFF;
+
+    for byte in bytes {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            let mask = (!(crc & 1)).wrapping_add(1);
+            crc = (crc >> 1) ^ (0xEDB88320 & mask);
+        }
+    }
+
+    !crc
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TextEncoding {
+    Petscii,
+    ScreenCodes,
+}
+
+impl TextEncoding {
+    pub fn to_ascii(&self, byte: u8) -> Option<char> {
+        match self {
+            TextEncoding::Petscii => petscii_to_ascii(byte),
+            TextEncoding::ScreenCodes => screen_code_to_ascii(byte),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Terminator {
+    None,
+    // L'ultimo carattere di ogni stringa ha il bit 7 alto, come nella
+    // tabella dei token del BASIC. Le stringhe consecutive sono raggruppate.
+    HighBit,
+    Zero,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StringCandidate {
+    pub offset: u32,
+    // Byte occupati, terminatore compreso.
+    pub length: u32,
+    pub encoding: TextEncoding,
+    pub terminator: Terminator,
+    pub text: String,
+    pub entropy: f64
+}
+
+// Il testo reale ha caratteri ripetuti ma non è una sequenza di riempimento.
+pub const MIN_TEXT_ENTROPY: f64 = 1.0;
+pub const MAX_TEXT_ENTROPY: f64 = 4.5;
+const MIN_LETTER_RATIO: f64 = 0.6;
+
+pub fn is_plausible_text(text: &str) -> bool {
+    if text.is_empty() {
+        return false;
+    }
+    let letters = text.chars().filter(|c| c.is_ascii_alphabetic() || *c == ' ').count();
+    let entropy = calculate_entropy(text);
+
+    letters as f64 / text.len() as f64 >= MIN_LETTER_RATIO && (MIN_TEXT_ENTROPY..=MAX_TEXT_ENTROPY).contains(&entropy)
+}
+
+fn scan_string(bytes: &[u8], start: usize, encoding: TextEncoding) -> Option<StringCandidate> {
+    let mut text = String::new();
+    let mut index = start;
+    let mut terminator = Terminator::None;
+    let mut word_start = 0;
+    // Fine dell'ultima stringa chiusa dal bit alto: (indice, caratteri).
+    let mut last_high_bit = (start, 0);
+
+    while index < bytes.len() {
+        let byte = bytes[index];
+        if let Some(c) = encoding.to_ascii(byte) {
+            text.push(c);
+            index += 1;
+            continue;
+        }
+
+        let high_bit_char = if byte & 0x80 != 0 { encoding.to_ascii(byte & 0x7F) } else { None };
+        match high_bit_char {
+            Some(c) if text.len() > word_start && terminator != Terminator::Zero => {
+                text.push(c);
+                index += 1;
+                terminator = Terminator::HighBit;
+                word_start = text.len();
+                last_high_bit = (index, text.len());
+                continue;
+            },
+            _ => {}
+        }
+        if byte == 0 && terminator == Terminator::None && !text.is_empty() {
+            index += 1;
+            terminator = Terminator::Zero;
+        }
+        break;
+    }
+
+    // Un gruppo di stringhe col bit alto finisce con l'ultima stringa chiusa.
+    if terminator == Terminator::HighBit {
+        index = last_high_bit.0;
+        text.truncate(last_high_bit.1);
+    }
+    if text.is_empty() {
+        return None;
+    }
+
+    Some(StringCandidate {
+        offset: start as u32,
+        length: (index - start) as u32,
+        encoding,
+        terminator,
+        entropy: calculate_entropy(&text),
+        text
+    })
+}
+
+// Cerca stringhe PETSCII o in codici schermo lunghe almeno min_chars
+// caratteri. In ogni posizione vince la codifica col candidato più lungo,
+// a parità il PETSCII. I candidati poco plausibili vengono scartati.
+pub fn find_strings(bytes: &[u8], min_chars: usize) -> Vec<StringCandidate> {
+    let mut found = Vec::new();
+    let mut offset = 0;
+
+    // Una sequenza scartata non viene riletta da ogni posizione interna:
+    // per ogni codifica si riprende dalla fine dell'ultima scartata.
+    let encodings = [TextEncoding::Petscii, TextEncoding::ScreenCodes];
+    let mut rejected_until = [0; 2];
+
+    while offset < bytes.len() {
+        let mut best: Option<StringCandidate> = None;
+        for (index, encoding) in encodings.iter().enumerate() {
+            if offset < rejected_until[index] {
+                continue;
+            }
+            let Some(candidate) = scan_string(bytes, offset, *encoding) else {
+                continue;
+            };
+            if candidate.text.len() < min_chars || !is_plausible_text(&candidate.text) {
+                rejected_until[index] = offset + candidate.length as usize;
+                continue;
+            }
+            if best.as_ref().is_none_or(|b| candidate.length > b.length) {
+                best = Some(candidate);
+            }
+        }
+
+        match best {
+            Some(candidate) => {
+                offset += candidate.length as usize;
+                found.push(candidate);
+            },
+            None => offset += 1
+        }
+    }
+
+    found
+}
@@ -0,0 +1,68 @@
+use std::time::{Duration, Instant};
+
+use super::{crc32, find_strings, is_plausible_text, petscii_to_ascii, screen_code_to_ascii, Terminator, TextEncoding};
+
+#[test]
+fn crc32_check_value() {
+    assert_eq!(crc32(b"123456789"), 0xCBF43926);
+    assert_eq!(crc32(&[]), 0);
+}
+
+#[test]
+fn text_encodings() {
+    assert_eq!(petscii_to_ascii(0x41), Some('A'));
+    assert_eq!(petscii_to_ascii(0x22), None);
+    assert_eq!(petscii_to_ascii(0x0D), None);
+    assert_eq!(screen_code_to_ascii(0x01), Some('A'));
+    assert_eq!(screen_code_to_ascii(0x00), Some('@'));
+    assert_eq!(screen_code_to_ascii(0x31), Some('1'));
+    assert_eq!(screen_code_to_ascii(0x41), None);
+}
+
+#[test]
+fn string_finder_encodings_and_terminators() {
+    let bytes = [
+        0xA9, 0x00,
+        b'H', b'E', b'L', b'L', b'O', 0x00,             // 2: zero-terminated
+        b'E', b'N', b'D' | 0x80, b'F', b'O', b'R' | 0x80,     // 8: bit alto
+        0xFF,
+        0x08, 0x05, 0x0C, 0x0C, 0x0F,                   // 15: codici schermo
+        b'A', b'A', b'A', b'A', b'A',                   // riempimento
+    ];
+    let strings = find_strings(&bytes, 4);
+    let found: Vec<(u32, u32, TextEncoding, Terminator, &str)> = strings.iter()
+        .map(|s| (s.offset, s.length, s.encoding, s.terminator, s.text.as_str()))
+        .collect();
+
+    assert_eq!(found, vec![
+        (2, 6, TextEncoding::Petscii, Terminator::Zero, "HELLO"),
+        (8, 6, TextEncoding::Petscii, Terminator::HighBit, "ENDFOR"),
+        (15, 5, TextEncoding::ScreenCodes, Terminator::None, "HELLO"),
+    ]);
+}
+
+#[test]
+fn string_finder_scales_on_large_images() {
+    // 24K di zeri sono tutti codici schermo '@' da leggere una volta sola.
+    let mut bytes = vec![0u8; 0x6000];
+    bytes[0x3000..0x3012].copy_from_slice(b"PRESS PLAY ON TAPE");
+
+    let started = Instant::now();
+    let strings = find_strings(&bytes, 8);
+    assert!(started.elapsed() < Duration::from_secs(2));
+    assert_eq!(strings.len(), 1);
+    assert_eq!((strings[0].offset, strings[0].text.as_str()), (0x3000, "PRESS PLAY ON TAPE"));
+
+    // Simboli stampabili senza lettere.
+    let noise: Vec<u8> = b"1;4?2/8:".iter().cycle().take(0x6000).copied().collect();
+    let started = Instant::now();
+    assert!(find_strings(&noise, 8).is_empty());
+    assert!(started.elapsed() < Duration::from_secs(2));
+}
+
+#[test]
+fn entropy_rejects_noise() {
+    assert!(is_plausible_text("PRESS PLAY ON TAPE"));
+    assert!(!is_plausible_text("AAAAAAAA"));
+    assert!(!is_plausible_text("1;4?2/8:"));
+}